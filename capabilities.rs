@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+use xcap::Monitor;
+
+/// What the current environment supports (for the settings UI and bug reports)
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Capabilities {
+    pub platform: &'static str,
+    /// "windows", "x11", "wayland" or "unknown"
+    pub session_type: String,
+    pub cursor_detection: bool,
+    pub monitor_count: usize,
+    pub hdr_monitors: bool,
+    pub clipboard_image: bool,
+    pub ocr_engine: Option<String>,
+    pub transcription_backends: Vec<String>,
+    /// Renderer used by the egui overlay
+    pub overlay_gpu_backend: &'static str,
+}
+
+/// Cached report + the monitor layout it was probed with
+static CACHE: Mutex<Option<(Vec<(i32, i32, u32, u32)>, Capabilities)>> = Mutex::new(None);
+
+fn session_type() -> String {
+    if cfg!(windows) {
+        return "windows".into();
+    }
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        return "wayland".into();
+    }
+    match std::env::var("XDG_SESSION_TYPE") {
+        Ok(kind) if !kind.is_empty() => kind.to_lowercase(),
+        _ if std::env::var_os("DISPLAY").is_some() => "x11".into(),
+        _ => "unknown".into(),
+    }
+}
+
+/// Cheap layout fingerprint - a change means monitors were (dis)connected or rearranged
+fn monitor_layout() -> Vec<(i32, i32, u32, u32)> {
+    Monitor::all()
+        .map(|monitors| {
+            monitors
+                .iter()
+                .map(|m| {
+                    (
+                        m.x().unwrap_or(0),
+                        m.y().unwrap_or(0),
+                        m.width().unwrap_or(0),
+                        m.height().unwrap_or(0),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn probe(layout: &[(i32, i32, u32, u32)]) -> Capabilities {
+    Capabilities {
+        platform: std::env::consts::OS,
+        session_type: session_type(),
+        cursor_detection: crate::screenshot_new::get_cursor_position().is_ok(),
+        monitor_count: layout.len(),
+        // Both capture backends return 8-bit SDR frames
+        hdr_monitors: false,
+        clipboard_image: true,
+        ocr_engine: None,
+        transcription_backends: Vec::new(),
+        overlay_gpu_backend: "glow",
+    }
+}
+
+/// Report environment capabilities; cached and re-probed when the monitor layout changes
+#[tauri::command]
+pub fn get_capabilities() -> Capabilities {
+    let layout = monitor_layout();
+    let mut cache = CACHE.lock().unwrap();
+
+    if let Some((cached_layout, caps)) = cache.as_ref() {
+        if *cached_layout == layout {
+            return caps.clone();
+        }
+    }
+
+    let caps = probe(&layout);
+    tracing::info!("🧪 Capabilities probed: {:?}", caps);
+    *cache = Some((layout, caps.clone()));
+    caps
+}
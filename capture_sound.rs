@@ -0,0 +1,58 @@
+use std::{
+    path::PathBuf,
+    sync::Mutex,
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+/// Burst/scheduled captures closer together than this play a single sound
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+static LAST_PLAYED: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Play the capture sound on a background thread (never blocks the caller).
+/// `file` = custom sound (wav/mp3/ogg/flac), None = built-in synthesized shutter click.
+/// Returns the playback thread (None = debounced) so a process about to exit can join it.
+pub fn play(volume: f32, file: Option<PathBuf>) -> Option<JoinHandle<()>> {
+    {
+        let mut last = LAST_PLAYED.lock().unwrap();
+        if last.is_some_and(|t| t.elapsed() < DEBOUNCE) {
+            return None;
+        }
+        *last = Some(Instant::now());
+    }
+
+    Some(std::thread::spawn(move || {
+        if let Err(e) = play_blocking(volume.clamp(0.0, 1.0), file) {
+            tracing::warn!("🔇 Capture sound failed: {}", e);
+        }
+    }))
+}
+
+fn play_blocking(volume: f32, file: Option<PathBuf>) -> Result<(), Box<dyn std::error::Error>> {
+    use rodio::{Decoder, OutputStream, Sink, Source};
+
+    // OutputStream is !Send - it has to live on this thread until playback ends
+    let (_stream, handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&handle)?;
+    sink.set_volume(volume);
+
+    match file {
+        Some(path) => {
+            let reader = std::io::BufReader::new(std::fs::File::open(&path)?);
+            sink.append(Decoder::new(reader)?);
+        }
+        None => {
+            // Default shutter: two short high clicks
+            let click = || rodio::source::SineWave::new(1800.0)
+                .take_duration(Duration::from_millis(25))
+                .fade_in(Duration::from_millis(3));
+            sink.append(click());
+            sink.append(rodio::source::Zero::<f32>::new(1, 48_000).take_duration(Duration::from_millis(40)));
+            sink.append(click().amplify(0.7));
+        }
+    }
+
+    sink.sleep_until_end();
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use tauri::{AppHandle, Manager};
+use std::{fs, path::PathBuf};
+
+/// Persistent bindings of the rebindable hotkeys (stored as JSON in app_data_dir)
+///
+/// Shortcuts are kept in the same string form the commands accept (e.g. "Ctrl+Shift+S").
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HotkeyConfig {
+    /// Overlay on the monitor under the cursor
+    pub screenshot_active: String,
+    /// Overlay on every monitor
+    pub screenshot_all: String,
+    /// Pause/resume text expansion
+    pub expansion_pause: String,
+    /// Whole virtual desktop straight to the clipboard
+    pub desktop_clipboard: String,
+    /// Overlay on one fixed monitor (None = unbound)
+    pub screenshot_monitor: Option<String>,
+    /// Positional monitor index for screenshot_monitor (as in list_monitors)
+    pub screenshot_monitor_index: usize,
+}
+
+impl Default for HotkeyConfig {
+    fn default() -> Self {
+        Self {
+            screenshot_active: "F10".into(),
+            screenshot_all: "F11".into(),
+            expansion_pause: "Ctrl+Alt+F9".into(),
+            desktop_clipboard: "Ctrl+F11".into(),
+            screenshot_monitor: None,
+            screenshot_monitor_index: 0,
+        }
+    }
+}
+
+pub fn config_path(app: &AppHandle) -> PathBuf {
+    app.path().app_data_dir().unwrap().join("hotkeys-config.json")
+}
+
+/// Load config, falling back to defaults when the file is missing or unreadable
+pub fn load_config(app: &AppHandle) -> HotkeyConfig {
+    fs::read_to_string(config_path(app))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_config(app: &AppHandle, config: &HotkeyConfig) -> std::result::Result<(), String> {
+    let path = config_path(app);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let json = serde_json::to_vec_pretty(config)
+        .map_err(|e| format!("Failed to serialize hotkey config: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
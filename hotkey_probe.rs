@@ -0,0 +1,110 @@
+use std::str::FromStr;
+use tauri::AppHandle;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+/// Who holds a global shortcut
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyOwner {
+    /// Nobody - the shortcut is free to register
+    Free,
+    /// Registered by this app
+    ThisApp,
+    /// Registered by another process whose name could not be determined
+    Unknown,
+}
+
+/// Probe a shortcut with RegisterHotKey: if the OS refuses, someone else owns it.
+/// Windows exposes no API to look up the owning process, so foreign owners are reported as Unknown.
+#[cfg(windows)]
+fn probe_foreign_owner(shortcut: &Shortcut) -> std::result::Result<HotkeyOwner, String> {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        RegisterHotKey, UnregisterHotKey, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT, MOD_WIN,
+    };
+    use tauri_plugin_global_shortcut::Modifiers;
+
+    // Arbitrary id, only held for the duration of the probe
+    const PROBE_ID: i32 = 0x7A30;
+
+    let vk = code_to_vk(shortcut.key)
+        .ok_or_else(|| format!("Unsupported key for probing: {:?}", shortcut.key))?;
+
+    let mut mods = MOD_NOREPEAT;
+    if shortcut.mods.contains(Modifiers::ALT) { mods |= MOD_ALT; }
+    if shortcut.mods.contains(Modifiers::CONTROL) { mods |= MOD_CONTROL; }
+    if shortcut.mods.contains(Modifiers::SHIFT) { mods |= MOD_SHIFT; }
+    if shortcut.mods.contains(Modifiers::SUPER) { mods |= MOD_WIN; }
+
+    unsafe {
+        if RegisterHotKey(None, PROBE_ID, mods, vk).is_ok() {
+            let _ = UnregisterHotKey(None, PROBE_ID);
+            Ok(HotkeyOwner::Free)
+        } else {
+            Ok(HotkeyOwner::Unknown)
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn probe_foreign_owner(_shortcut: &Shortcut) -> std::result::Result<HotkeyOwner, String> {
+    Ok(HotkeyOwner::Unknown)
+}
+
+/// Virtual-key codes for the keys we allow as hotkeys
+#[cfg(windows)]
+fn code_to_vk(code: tauri_plugin_global_shortcut::Code) -> Option<u32> {
+    use tauri_plugin_global_shortcut::Code;
+
+    let vk = match code {
+        Code::F1 => 0x70, Code::F2 => 0x71, Code::F3 => 0x72, Code::F4 => 0x73,
+        Code::F5 => 0x74, Code::F6 => 0x75, Code::F7 => 0x76, Code::F8 => 0x77,
+        Code::F9 => 0x78, Code::F10 => 0x79, Code::F11 => 0x7A, Code::F12 => 0x7B,
+        Code::Home => 0x24, Code::End => 0x23, Code::PageUp => 0x21, Code::PageDown => 0x22,
+        Code::Insert => 0x2D, Code::Delete => 0x2E, Code::Space => 0x20,
+        Code::PrintScreen => 0x2C, Code::Pause => 0x13, Code::ScrollLock => 0x91,
+        Code::Digit0 => 0x30, Code::Digit1 => 0x31, Code::Digit2 => 0x32, Code::Digit3 => 0x33,
+        Code::Digit4 => 0x34, Code::Digit5 => 0x35, Code::Digit6 => 0x36, Code::Digit7 => 0x37,
+        Code::Digit8 => 0x38, Code::Digit9 => 0x39,
+        _ => {
+            // Letters: KeyA..KeyZ map to 'A'..'Z'
+            let name = format!("{:?}", code);
+            let letter = name.strip_prefix("Key")?;
+            let c = letter.chars().next()?;
+            if letter.len() == 1 && c.is_ascii_uppercase() { c as u32 } else { return None; }
+        }
+    };
+    Some(vk)
+}
+
+/// Whether the shortcut's main key is physically held right now (push-to-talk watchdog).
+/// Keys we can't map are reported as held so the caller falls back to its own timeout.
+#[cfg(windows)]
+pub fn key_held(shortcut: &Shortcut) -> bool {
+    use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+
+    match code_to_vk(shortcut.key) {
+        // High bit = key currently down
+        Some(vk) => unsafe { GetAsyncKeyState(vk as i32) as u16 & 0x8000 != 0 },
+        None => true,
+    }
+}
+
+#[cfg(not(windows))]
+pub fn key_held(_shortcut: &Shortcut) -> bool {
+    true
+}
+
+/// Best-effort lookup of who owns a shortcut (e.g. after "F10 register failed")
+#[tauri::command]
+pub fn identify_hotkey_owner(app: AppHandle, shortcut: String) -> std::result::Result<HotkeyOwner, String> {
+    let parsed = Shortcut::from_str(&shortcut)
+        .map_err(|e| format!("Invalid shortcut '{}': {}", shortcut, e))?;
+
+    if app.global_shortcut().is_registered(parsed) {
+        return Ok(HotkeyOwner::ThisApp);
+    }
+
+    let owner = probe_foreign_owner(&parsed)?;
+    tracing::info!("🔎 Hotkey {} owner: {:?}", shortcut, owner);
+    Ok(owner)
+}
@@ -1,22 +1,618 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod screenshot_new;
+mod screenshot_config;
+mod capabilities;
+mod settings;
+mod hotkey_probe;
+mod hotkey_config;
+mod vtt_config;
+mod ocr;
+mod ocr_engine;
+mod capture_sound;
+mod postprocess;
+mod region;
+mod screenshot_settings;
 mod simple_expansion;
 mod voice_to_text;
 mod hotkeys;
 mod keyboard;
 
+use std::str::FromStr;
 use std::sync::{Arc, RwLock, Once};
+use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::{Emitter, Manager};
 use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut};
 use simple_expansion::SimpleExpansionState;
+use vtt_config::VttMode;
+// `crate::image` for the shared #[path] modules (postprocess.rs, region.rs, screenshot_settings.rs); overlay_egui binds xcap's instead
+use screenshots::image;
 
 #[derive(Clone)]
 pub struct HotkeysState {
   vtt: Arc<RwLock<Shortcut>>,
+  screenshot_active: Arc<RwLock<Shortcut>>,
+  screenshot_all: Arc<RwLock<Shortcut>>,
+  expansion_pause: Arc<RwLock<Shortcut>>,
+  desktop_clipboard: Arc<RwLock<Shortcut>>,
+  /// Optional fixed-monitor overlay hotkey and its monitor index (unbound by default)
+  screenshot_monitor: Arc<RwLock<Option<(Shortcut, usize)>>>,
+  muted: Arc<AtomicBool>,
+  /// (hotkey, error) for shortcuts that failed to register on the last full registration
+  conflicts: Arc<RwLock<Vec<(String, String)>>>,
 }
 
-fn default_vtt() -> Shortcut { Shortcut::new(Some(Modifiers::empty()), Code::F9) }
+// Home (changed from F9 due to hotkey conflict), rebindable with set_vtt_hotkey
+fn default_vtt() -> Shortcut { Shortcut::new(Some(Modifiers::empty()), Code::Home) }
+fn default_screenshot_active() -> Shortcut { Shortcut::new(Some(Modifiers::empty()), Code::F10) }
+fn default_screenshot_all() -> Shortcut { Shortcut::new(Some(Modifiers::empty()), Code::F11) }
+fn default_expansion_pause() -> Shortcut { Shortcut::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::F9) }
+fn default_desktop_clipboard() -> Shortcut { Shortcut::new(Some(Modifiers::CONTROL), Code::F11) }
+
+/// Parse a persisted binding, falling back to the built-in default if the file holds garbage
+fn configured_shortcut(value: &str, default: Shortcut) -> Shortcut {
+    Shortcut::from_str(value).unwrap_or_else(|e| {
+        tracing::warn!("⚠️ Invalid hotkey '{}' in config ({}), using {:?}", value, e, default);
+        default
+    })
+}
+
+/// Hotkeys the user can rebind at runtime
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HotkeySlot {
+    Vtt,
+    ScreenshotActive,
+    ScreenshotAll,
+    ExpansionPause,
+    DesktopClipboard,
+}
+
+impl HotkeySlot {
+    const ALL: [HotkeySlot; 5] = [
+        HotkeySlot::Vtt, HotkeySlot::ScreenshotActive, HotkeySlot::ScreenshotAll, HotkeySlot::ExpansionPause,
+        HotkeySlot::DesktopClipboard,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            HotkeySlot::Vtt => "VTT",
+            HotkeySlot::ScreenshotActive => "Active Monitor",
+            HotkeySlot::ScreenshotAll => "All Monitors",
+            HotkeySlot::ExpansionPause => "Expansion Pause",
+            HotkeySlot::DesktopClipboard => "Desktop → Clipboard",
+        }
+    }
+
+    /// What a press does: emit to the main window, or toggle expansion pause / copy the desktop directly
+    fn on_press(self, app: &tauri::AppHandle) {
+        let event = match self {
+            HotkeySlot::Vtt => {
+                on_vtt_press(app);
+                return;
+            }
+            HotkeySlot::ScreenshotActive => "screenshot-active-monitor",
+            HotkeySlot::ScreenshotAll => "screenshot-all-monitors",
+            HotkeySlot::ExpansionPause => {
+                let paused = app.state::<SimpleExpansionState>().paused.load(Ordering::SeqCst);
+                set_expansion_paused(app.clone(), app.state::<SimpleExpansionState>(), !paused);
+                return;
+            }
+            // Whole virtual desktop straight to clipboard (no overlay, no file)
+            HotkeySlot::DesktopClipboard => {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = screenshot_new::capture_virtual_desktop_to_clipboard(app).await {
+                        tracing::error!("❌ Desktop capture failed: {}", e);
+                    }
+                });
+                return;
+            }
+        };
+        // Screenshot flow runs from the main window - bring it forward first
+        if let Some(win) = app.get_webview_window("main") {
+            let _ = win.set_focus();
+        }
+        let _ = app.emit_to("main", event, ());
+    }
+
+    fn binding(self, state: &HotkeysState) -> &Arc<RwLock<Shortcut>> {
+        match self {
+            HotkeySlot::Vtt => &state.vtt,
+            HotkeySlot::ScreenshotActive => &state.screenshot_active,
+            HotkeySlot::ScreenshotAll => &state.screenshot_all,
+            HotkeySlot::ExpansionPause => &state.expansion_pause,
+            HotkeySlot::DesktopClipboard => &state.desktop_clipboard,
+        }
+    }
+
+    fn register(self, app: &tauri::AppHandle, shortcut: Shortcut) -> Result<(), tauri_plugin_global_shortcut::Error> {
+        app.global_shortcut().on_shortcut(shortcut, {
+            let app = app.clone();
+            move |_app, shortcut, event| {
+                tracing::info!("🎹 {:?} ({}) {:?}", shortcut, self.label(), event);
+                // Reaguj na wciśnięcie (Pressed); puszczenie (Released) tylko dla push-to-talk
+                let event = format!("{:?}", event);
+                if event.contains("Pressed") {
+                    self.on_press(&app);
+                } else if event.contains("Released") && self == HotkeySlot::Vtt {
+                    on_vtt_release(&app);
+                }
+            }
+        }).map_err(|e| {
+            tracing::error!("❌ {} hotkey {:?} register failed: {}", self.label(), shortcut, e);
+            e
+        })
+    }
+}
+
+/// Whether VTT recording is running, as driven by the hotkey (`vtt:start` / `vtt:stop`)
+static VTT_RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Push-to-talk safety cap in case neither Released nor the key poll ever reports the release
+const PTT_MAX_SECONDS: u64 = 120;
+
+/// Returns false if recording was already running
+fn vtt_start(app: &tauri::AppHandle) -> bool {
+    if VTT_RECORDING.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    tracing::info!("🎙️ VTT start");
+    let _ = app.emit_to("main", "vtt:start", ());
+    true
+}
+
+fn vtt_stop(app: &tauri::AppHandle) {
+    if VTT_RECORDING.swap(false, Ordering::SeqCst) {
+        tracing::info!("🎙️ VTT stop");
+        let _ = app.emit_to("main", "vtt:stop", ());
+    }
+}
+
+fn on_vtt_press(app: &tauri::AppHandle) {
+    let mode = app.state::<vtt_config::VttConfigState>().0.read().unwrap().mode;
+    match mode {
+        VttMode::Toggle => {
+            // Legacy event - older frontends toggle on this themselves
+            let _ = app.emit_to("main", "vtt:hotkey", ());
+            if VTT_RECORDING.load(Ordering::SeqCst) {
+                vtt_stop(app);
+            } else {
+                vtt_start(app);
+            }
+        }
+        VttMode::PushToTalk => {
+            // Key repeat while held re-sends Pressed - only the first one starts a watchdog
+            if vtt_start(app) {
+                spawn_ptt_watchdog(app.clone());
+            }
+        }
+    }
+}
+
+fn on_vtt_release(app: &tauri::AppHandle) {
+    let mode = app.state::<vtt_config::VttConfigState>().0.read().unwrap().mode;
+    if mode == VttMode::PushToTalk {
+        vtt_stop(app);
+    }
+}
+
+/// Released can get lost (focus change, UAC prompt, lock screen) - poll the physical key
+/// and stop recording once it's up, with PTT_MAX_SECONDS as the last resort.
+fn spawn_ptt_watchdog(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let started = std::time::Instant::now();
+        while VTT_RECORDING.load(Ordering::SeqCst) {
+            std::thread::sleep(std::time::Duration::from_millis(150));
+            let shortcut = *app.state::<HotkeysState>().vtt.read().unwrap();
+            if !hotkey_probe::key_held(&shortcut) {
+                tracing::warn!("⚠️ VTT key released without a Released event - stopping");
+                vtt_stop(&app);
+            } else if started.elapsed().as_secs() >= PTT_MAX_SECONDS {
+                tracing::warn!("⚠️ Push-to-talk held for {}s - stopping", PTT_MAX_SECONDS);
+                vtt_stop(&app);
+            }
+        }
+    });
+}
+
+/// Fixed shortcuts owned by the app (unregistered as a group with the rebindable ones when muted)
+const APP_HOTKEYS: [&str; 3] = [
+    COLOR_PICKER_HOTKEY, ACTIVE_WINDOW_HOTKEY, DELAYED_CAPTURE_HOTKEY,
+];
+
+/// Overlay straight into color picker mode on the active monitor
+const COLOR_PICKER_HOTKEY: &str = "Ctrl+F10";
+
+/// Foreground window → file, no overlay
+const ACTIVE_WINDOW_HOTKEY: &str = "Alt+F10";
+
+/// Active-monitor overlay after DELAYED_CAPTURE_SECONDS
+const DELAYED_CAPTURE_HOTKEY: &str = "Shift+F10";
+const DELAYED_CAPTURE_SECONDS: u32 = 3;
+
+/// Toggles game mode; deliberately not part of APP_HOTKEYS so it can unmute
+const MUTE_TOGGLE_HOTKEY: &str = "Ctrl+Alt+F12";
+
+/// Fixed-monitor overlay hotkey; the monitor index is read from HotkeysState on every press
+fn register_monitor_hotkey(app: &tauri::AppHandle, shortcut: Shortcut) -> Result<(), tauri_plugin_global_shortcut::Error> {
+    app.global_shortcut().on_shortcut(shortcut, {
+        let app = app.clone();
+        move |_app, shortcut, event| {
+            tracing::info!("🎹 {:?} (Fixed Monitor) {:?}", shortcut, event);
+            if !format!("{:?}", event).contains("Pressed") {
+                return;
+            }
+            let Some((_, index)) = *app.state::<HotkeysState>().screenshot_monitor.read().unwrap() else {
+                return;
+            };
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = screenshot_new::launch_screenshot_overlay_monitor(app, index).await {
+                    tracing::error!("❌ Monitor {} capture failed: {}", index, e);
+                }
+            });
+        }
+    }).map_err(|e| {
+        tracing::error!("❌ Fixed Monitor hotkey {:?} register failed: {}", shortcut, e);
+        e
+    })
+}
+
+/// Register the VTT and screenshot global shortcuts.
+/// Keeps going past failures (key owned by another app) and returns them as (hotkey, error) pairs.
+fn register_global_hotkeys(app: &tauri::AppHandle) -> Vec<(String, String)> {
+    let gs = app.global_shortcut();
+    let mut failures = Vec::new();
+
+    // VTT (Home), active monitor (F10), all monitors (F11), expansion pause (Ctrl+Alt+F9),
+    // desktop → clipboard (Ctrl+F11) → current bindings from HotkeysState
+    let state = app.state::<HotkeysState>();
+    for slot in HotkeySlot::ALL {
+        let shortcut = *slot.binding(&state).read().unwrap();
+        if let Err(e) = slot.register(app, shortcut) {
+            failures.push((shortcut.into_string(), e.to_string()));
+        }
+    }
+
+    // Optional fixed-monitor overlay
+    let monitor_hotkey = *state.screenshot_monitor.read().unwrap();
+    if let Some((shortcut, _)) = monitor_hotkey {
+        if let Err(e) = register_monitor_hotkey(app, shortcut) {
+            failures.push((shortcut.into_string(), e.to_string()));
+        }
+    }
+
+    // Ctrl+F10 → color picker on the active monitor
+    gs.on_shortcut(COLOR_PICKER_HOTKEY, {
+        let app = app.clone();
+        move |_app, _shortcut, event| {
+            tracing::info!("🎹 {} (Color Picker) {:?}", COLOR_PICKER_HOTKEY, event);
+            if format!("{:?}", event).contains("Pressed") {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = screenshot_new::launch_color_picker(app).await {
+                        tracing::error!("❌ Color picker launch failed: {}", e);
+                    }
+                });
+            }
+        }
+    }).unwrap_or_else(|e| {
+        tracing::error!("❌ {} register failed: {}", COLOR_PICKER_HOTKEY, e);
+        failures.push((COLOR_PICKER_HOTKEY.to_string(), e.to_string()));
+    });
+
+    // Alt+F10 → active window straight to file
+    gs.on_shortcut(ACTIVE_WINDOW_HOTKEY, {
+        let app = app.clone();
+        move |_app, _shortcut, event| {
+            tracing::info!("🎹 {} (Active Window) {:?}", ACTIVE_WINDOW_HOTKEY, event);
+            if format!("{:?}", event).contains("Pressed") {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = screenshot_new::capture_active_window(app).await {
+                        tracing::error!("❌ Active window capture failed: {}", e);
+                    }
+                });
+            }
+        }
+    }).unwrap_or_else(|e| {
+        tracing::error!("❌ {} register failed: {}", ACTIVE_WINDOW_HOTKEY, e);
+        failures.push((ACTIVE_WINDOW_HOTKEY.to_string(), e.to_string()));
+    });
+
+    // Shift+F10 → active monitor overlay after a countdown
+    gs.on_shortcut(DELAYED_CAPTURE_HOTKEY, {
+        let app = app.clone();
+        move |_app, _shortcut, event| {
+            tracing::info!("🎹 {} (Delayed Capture) {:?}", DELAYED_CAPTURE_HOTKEY, event);
+            if format!("{:?}", event).contains("Pressed") {
+                if let Err(e) = screenshot_new::launch_screenshot_overlay_delayed(app.clone(), DELAYED_CAPTURE_SECONDS) {
+                    tracing::error!("❌ Delayed capture failed: {}", e);
+                }
+            }
+        }
+    }).unwrap_or_else(|e| {
+        tracing::error!("❌ {} register failed: {}", DELAYED_CAPTURE_HOTKEY, e);
+        failures.push((DELAYED_CAPTURE_HOTKEY.to_string(), e.to_string()));
+    });
+
+    failures
+}
+
+/// Unregister the VTT and screenshot shortcuts so the keys pass through to other apps.
+/// Shortcuts that never registered (conflicts) are skipped.
+fn unregister_global_hotkeys(app: &tauri::AppHandle) -> Result<(), tauri_plugin_global_shortcut::Error> {
+    let gs = app.global_shortcut();
+    let state = app.state::<HotkeysState>();
+    for slot in HotkeySlot::ALL {
+        let shortcut = *slot.binding(&state).read().unwrap();
+        if gs.is_registered(shortcut) {
+            gs.unregister(shortcut)?;
+        }
+    }
+    let monitor_hotkey = *state.screenshot_monitor.read().unwrap();
+    if let Some((shortcut, _)) = monitor_hotkey {
+        if gs.is_registered(shortcut) {
+            gs.unregister(shortcut)?;
+        }
+    }
+    for hotkey in APP_HOTKEYS {
+        if gs.is_registered(hotkey) {
+            gs.unregister(hotkey)?;
+        }
+    }
+    Ok(())
+}
+
+/// Remember failed registrations of a `register_global_hotkeys` pass for `list_hotkey_conflicts`
+/// and tell the UI about them. Replaces that pass's old entries only - a MUTE_TOGGLE_HOTKEY
+/// conflict from startup stays listed, since the toggle is never registered again.
+fn record_hotkey_conflicts(app: &tauri::AppHandle, conflicts: Vec<(String, String)>) {
+    let state = app.state::<HotkeysState>();
+    let mut recorded = state.conflicts.write().unwrap();
+    recorded.retain(|(hotkey, _)| hotkey == MUTE_TOGGLE_HOTKEY);
+    if !conflicts.is_empty() {
+        tracing::warn!("⚠️ {} hotkey(s) failed to register: {:?}", conflicts.len(), conflicts);
+    }
+    recorded.extend(conflicts);
+    if !recorded.is_empty() {
+        let _ = app.emit("hotkey-conflicts", &*recorded);
+    }
+}
+
+/// Game mode: unregister (not just ignore) all app hotkeys while muted. Session-only, not persisted.
+#[tauri::command]
+fn set_hotkeys_muted(app: tauri::AppHandle, state: tauri::State<'_, HotkeysState>, muted: bool) -> Result<(), String> {
+    if state.muted.load(Ordering::SeqCst) == muted {
+        return Ok(());
+    }
+
+    if muted {
+        if let Err(e) = unregister_global_hotkeys(&app) {
+            // Stay unmuted with every hotkey working: re-register the ones already removed.
+            // The ones still registered fail as duplicates - those aren't conflicts.
+            let gs = app.global_shortcut();
+            let conflicts = register_global_hotkeys(&app)
+                .into_iter()
+                .filter(|(hotkey, _)| !gs.is_registered(hotkey.as_str()))
+                .collect();
+            record_hotkey_conflicts(&app, conflicts);
+            return Err(format!("Failed to unregister hotkeys: {}", e));
+        }
+        // No Released will arrive for an unregistered key
+        vtt_stop(&app);
+    } else {
+        // Partial success is fine - whatever failed is reported via `hotkey-conflicts`
+        record_hotkey_conflicts(&app, register_global_hotkeys(&app));
+    }
+
+    state.muted.store(muted, Ordering::SeqCst);
+    tracing::info!("🎮 Hotkeys {}", if muted { "muted (game mode)" } else { "unmuted" });
+    let _ = app.emit("hotkeys-muted-changed", muted);
+    Ok(())
+}
+
+/// Feature already bound to `shortcut` (other than `slot` itself; None = the fixed-monitor hotkey), if any
+fn hotkey_conflict(state: &HotkeysState, slot: Option<HotkeySlot>, shortcut: Shortcut) -> Option<&'static str> {
+    for other in HotkeySlot::ALL {
+        if Some(other) != slot && *other.binding(state).read().unwrap() == shortcut {
+            return Some(other.label());
+        }
+    }
+    if slot.is_some() && state.screenshot_monitor.read().unwrap().is_some_and(|(bound, _)| bound == shortcut) {
+        return Some("Fixed Monitor");
+    }
+    APP_HOTKEYS
+        .into_iter()
+        .chain([MUTE_TOGGLE_HOTKEY])
+        .find(|fixed| Shortcut::from_str(fixed).is_ok_and(|s| s == shortcut))
+}
+
+/// Swap the binding of a rebindable hotkey. On any error the previous binding stays active.
+fn rebind_hotkey(app: &tauri::AppHandle, state: &HotkeysState, slot: HotkeySlot, shortcut: &str) -> Result<(), String> {
+    let new = Shortcut::from_str(shortcut)
+        .map_err(|e| format!("Invalid shortcut '{}': {}", shortcut, e))?;
+    let old = *slot.binding(state).read().unwrap();
+    if new == old {
+        return Ok(());
+    }
+
+    if let Some(owner) = hotkey_conflict(state, Some(slot), new) {
+        return Err(format!("'{}' is already bound to {}", shortcut, owner));
+    }
+
+    // While muted nothing is registered - just remember the binding for unmute
+    if !state.muted.load(Ordering::SeqCst) {
+        let gs = app.global_shortcut();
+        // The old binding may never have registered (startup conflict)
+        let was_registered = gs.is_registered(old);
+        if was_registered {
+            gs.unregister(old)
+                .map_err(|e| format!("Failed to unregister previous {} hotkey: {}", slot.label(), e))?;
+        }
+        if let Err(e) = slot.register(app, new) {
+            if was_registered {
+                if let Err(restore) = slot.register(app, old) {
+                    tracing::error!("❌ Failed to restore previous {} hotkey: {}", slot.label(), restore);
+                }
+            }
+            return Err(format!("Failed to register '{}' (probably used by another app): {}", shortcut, e));
+        }
+    }
+
+    // Rebinding away from a conflicting key resolves that conflict
+    let old_name = old.into_string();
+    state.conflicts.write().unwrap().retain(|(hotkey, _)| *hotkey != old_name);
+
+    *slot.binding(state).write().unwrap() = new;
+    tracing::info!("🎹 {} hotkey → {}", slot.label(), shortcut);
+    Ok(())
+}
+
+/// Rebind the VTT hotkey (e.g. "Ctrl+Shift+V"). On any error the previous binding stays active.
+#[tauri::command]
+fn set_vtt_hotkey(app: tauri::AppHandle, state: tauri::State<'_, HotkeysState>, shortcut: String) -> Result<(), String> {
+    rebind_hotkey(&app, &state, HotkeySlot::Vtt, &shortcut)
+}
+
+/// Rebind the active-monitor screenshot hotkey (default F10) and persist it
+#[tauri::command]
+fn set_screenshot_active_hotkey(app: tauri::AppHandle, state: tauri::State<'_, HotkeysState>, shortcut: String) -> Result<(), String> {
+    rebind_hotkey(&app, &state, HotkeySlot::ScreenshotActive, &shortcut)?;
+    let mut config = hotkey_config::load_config(&app);
+    config.screenshot_active = shortcut;
+    hotkey_config::save_config(&app, &config)
+}
+
+/// Rebind the all-monitors screenshot hotkey (default F11) and persist it
+#[tauri::command]
+fn set_screenshot_all_hotkey(app: tauri::AppHandle, state: tauri::State<'_, HotkeysState>, shortcut: String) -> Result<(), String> {
+    rebind_hotkey(&app, &state, HotkeySlot::ScreenshotAll, &shortcut)?;
+    let mut config = hotkey_config::load_config(&app);
+    config.screenshot_all = shortcut;
+    hotkey_config::save_config(&app, &config)
+}
+
+/// Rebind the expansion pause toggle (default Ctrl+Alt+F9) and persist it
+#[tauri::command]
+fn set_expansion_pause_hotkey(app: tauri::AppHandle, state: tauri::State<'_, HotkeysState>, shortcut: String) -> Result<(), String> {
+    rebind_hotkey(&app, &state, HotkeySlot::ExpansionPause, &shortcut)?;
+    let mut config = hotkey_config::load_config(&app);
+    config.expansion_pause = shortcut;
+    hotkey_config::save_config(&app, &config)
+}
+
+/// Rebind the desktop → clipboard hotkey (default Ctrl+F11) and persist it
+#[tauri::command]
+fn set_desktop_clipboard_hotkey(app: tauri::AppHandle, state: tauri::State<'_, HotkeysState>, shortcut: String) -> Result<(), String> {
+    rebind_hotkey(&app, &state, HotkeySlot::DesktopClipboard, &shortcut)?;
+    let mut config = hotkey_config::load_config(&app);
+    config.desktop_clipboard = shortcut;
+    hotkey_config::save_config(&app, &config)
+}
+
+/// Bind the fixed-monitor overlay hotkey to `monitor_index` (shortcut None = unbind) and persist it.
+/// On any error the previous binding stays active.
+#[tauri::command]
+fn set_screenshot_monitor_hotkey(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, HotkeysState>,
+    shortcut: Option<String>,
+    monitor_index: usize,
+) -> Result<(), String> {
+    let new = shortcut
+        .as_deref()
+        .map(|s| Shortcut::from_str(s).map_err(|e| format!("Invalid shortcut '{}': {}", s, e)))
+        .transpose()?;
+    if let Some(new) = new {
+        if let Some(owner) = hotkey_conflict(&state, None, new) {
+            return Err(format!("'{}' is already bound to {}", new.into_string(), owner));
+        }
+    }
+
+    let old = state.screenshot_monitor.read().unwrap().map(|(shortcut, _)| shortcut);
+    if old != new && !state.muted.load(Ordering::SeqCst) {
+        let gs = app.global_shortcut();
+        let was_registered = old.is_some_and(|old| gs.is_registered(old));
+        if let Some(old) = old.filter(|_| was_registered) {
+            gs.unregister(old)
+                .map_err(|e| format!("Failed to unregister previous Fixed Monitor hotkey: {}", e))?;
+        }
+        if let Some(new) = new {
+            if let Err(e) = register_monitor_hotkey(&app, new) {
+                if let Some(old) = old.filter(|_| was_registered) {
+                    if let Err(restore) = register_monitor_hotkey(&app, old) {
+                        tracing::error!("❌ Failed to restore previous Fixed Monitor hotkey: {}", restore);
+                    }
+                }
+                return Err(format!("Failed to register '{}' (probably used by another app): {}", new.into_string(), e));
+            }
+        }
+    }
+
+    // Rebinding away from a conflicting key resolves that conflict
+    if let Some(old) = old.filter(|&old| Some(old) != new) {
+        let old_name = old.into_string();
+        state.conflicts.write().unwrap().retain(|(hotkey, _)| *hotkey != old_name);
+    }
+
+    *state.screenshot_monitor.write().unwrap() = new.map(|shortcut| (shortcut, monitor_index));
+    tracing::info!("🎹 Fixed Monitor hotkey → {:?} (monitor {})", shortcut, monitor_index);
+
+    let mut config = hotkey_config::load_config(&app);
+    config.screenshot_monitor = shortcut;
+    config.screenshot_monitor_index = monitor_index;
+    hotkey_config::save_config(&app, &config)
+}
+
+/// Restore the built-in bindings of all rebindable hotkeys (and drop the persisted ones)
+fn reset_hotkey_bindings(app: &tauri::AppHandle) -> Result<(), String> {
+    let state = app.state::<HotkeysState>();
+    let muted = state.muted.load(Ordering::SeqCst);
+
+    // Unregister everything first so swapped bindings (e.g. F10 <-> F11) can't collide mid-reset
+    if !muted {
+        unregister_global_hotkeys(app).map_err(|e| format!("Failed to unregister hotkeys: {}", e))?;
+    }
+    *state.vtt.write().unwrap() = default_vtt();
+    *state.screenshot_active.write().unwrap() = default_screenshot_active();
+    *state.screenshot_all.write().unwrap() = default_screenshot_all();
+    *state.expansion_pause.write().unwrap() = default_expansion_pause();
+    *state.desktop_clipboard.write().unwrap() = default_desktop_clipboard();
+    *state.screenshot_monitor.write().unwrap() = None;
+    if !muted {
+        record_hotkey_conflicts(app, register_global_hotkeys(app));
+    }
+
+    hotkey_config::save_config(app, &hotkey_config::HotkeyConfig::default())
+}
+
+#[tauri::command]
+fn get_hotkeys_muted(state: tauri::State<'_, HotkeysState>) -> bool {
+    state.muted.load(Ordering::SeqCst)
+}
+
+/// Pause/resume text expansion. The listener checks the shared flag on every keystroke,
+/// so the change applies to the very next key.
+#[tauri::command]
+fn set_expansion_paused(app: tauri::AppHandle, state: tauri::State<'_, SimpleExpansionState>, paused: bool) {
+    if state.paused.swap(paused, Ordering::SeqCst) == paused {
+        return;
+    }
+    tracing::info!("⏸️ Text expansion {}", if paused { "paused" } else { "resumed" });
+    let _ = app.emit("expansion-paused-changed", paused);
+}
+
+#[tauri::command]
+fn is_expansion_paused(state: tauri::State<'_, SimpleExpansionState>) -> bool {
+    state.paused.load(Ordering::SeqCst)
+}
+
+/// Shortcuts that failed to register (hotkey, error) - the settings UI prompts to rebind these
+#[tauri::command]
+fn list_hotkey_conflicts(state: tauri::State<'_, HotkeysState>) -> Vec<(String, String)> {
+    state.conflicts.read().unwrap().clone()
+}
 
 static EXPANSION_LISTENER_ONCE: Once = Once::new();
 
@@ -32,7 +628,16 @@ fn main() {
 
     tauri::Builder::default()
         .manage(expansion_state.clone())
-        .manage(HotkeysState { vtt: Arc::new(RwLock::new(default_vtt())) })
+        .manage(HotkeysState {
+            vtt: Arc::new(RwLock::new(default_vtt())),
+            screenshot_active: Arc::new(RwLock::new(default_screenshot_active())),
+            screenshot_all: Arc::new(RwLock::new(default_screenshot_all())),
+            expansion_pause: Arc::new(RwLock::new(default_expansion_pause())),
+            desktop_clipboard: Arc::new(RwLock::new(default_desktop_clipboard())),
+            screenshot_monitor: Arc::new(RwLock::new(None)),
+            muted: Arc::new(AtomicBool::new(false)),
+            conflicts: Arc::new(RwLock::new(Vec::new())),
+        })
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_clipboard_manager::init())
@@ -43,56 +648,40 @@ fn main() {
             let loaded = expansion_state.load_from_file(None).unwrap_or(0);
             tracing::info!("[TEXP] Auto-loaded {} shortcuts from default file", loaded);
 
-            let gs = app.global_shortcut();
+            app.manage(vtt_config::VttConfigState(RwLock::new(vtt_config::load_config(app.handle()))));
 
-            // Home → VTT (changed from F9 due to hotkey conflict)
-            gs.on_shortcut("Home", {
-                let app = app.handle().clone();
-                move |_app, _shortcut, event| {
-                    tracing::info!("🎹 Home (VTT) {:?}", event);
-                    // Reaguj tylko na wciśnięcie (Pressed)
-                    if format!("{:?}", event).contains("Pressed") {
-                        let _ = app.emit_to("main", "vtt:hotkey", ());
-                    }
-                }
-            }).map_err(|e| {
-                tracing::error!("❌ Home register failed: {}", e);
-                e
-            })?;
-
-            // F10 → screenshot active monitor (where cursor is) - NEW PRIMARY HOTKEY
-            gs.on_shortcut("F10", {
-                let app = app.handle().clone();
-                move |_app, _shortcut, event| {
-                    tracing::info!("🎹 F10 (Active Monitor) {:?}", event);
-                    if format!("{:?}", event).contains("Pressed") {
-                        if let Some(win) = app.get_webview_window("main") {
-                            let _ = win.set_focus();
-                        }
-                        let _ = app.emit_to("main", "screenshot-active-monitor", ());
-                    }
-                }
-            }).map_err(|e| {
-                tracing::error!("❌ F10 register failed: {}", e);
-                e
-            })?;
-
-            // F11 → screenshot ALL monitors - FOR POWER USERS
-            gs.on_shortcut("F11", {
-                let app = app.handle().clone();
-                move |_app, _shortcut, event| {
-                    tracing::info!("🎹 F11 (All Monitors) {:?}", event);
-                    if format!("{:?}", event).contains("Pressed") {
-                        if let Some(win) = app.get_webview_window("main") {
-                            let _ = win.set_focus();
-                        }
-                        let _ = app.emit_to("main", "screenshot-all-monitors", ());
+            // Persisted F10/F11/expansion pause/desktop clipboard rebinds (app_data_dir is only reachable once the app exists)
+            let hotkeys = hotkey_config::load_config(app.handle());
+            {
+                let state = app.state::<HotkeysState>();
+                *state.screenshot_active.write().unwrap() = configured_shortcut(&hotkeys.screenshot_active, default_screenshot_active());
+                *state.screenshot_all.write().unwrap() = configured_shortcut(&hotkeys.screenshot_all, default_screenshot_all());
+                *state.expansion_pause.write().unwrap() = configured_shortcut(&hotkeys.expansion_pause, default_expansion_pause());
+                *state.desktop_clipboard.write().unwrap() = configured_shortcut(&hotkeys.desktop_clipboard, default_desktop_clipboard());
+                *state.screenshot_monitor.write().unwrap() = hotkeys.screenshot_monitor.as_deref().and_then(|value| {
+                    Shortcut::from_str(value)
+                        .map_err(|e| tracing::warn!("⚠️ Invalid fixed-monitor hotkey '{}' in config ({}), leaving it unbound", value, e))
+                        .ok()
+                        .map(|shortcut| (shortcut, hotkeys.screenshot_monitor_index))
+                });
+            }
+
+            // Don't abort startup when another app owns a key - run with whatever registered
+            let mut conflicts = register_global_hotkeys(app.handle());
+
+            // Ctrl+Alt+F12 → mute/unmute all other hotkeys (game mode), stays registered while muted
+            app.global_shortcut().on_shortcut(MUTE_TOGGLE_HOTKEY, |app, _shortcut, event| {
+                if format!("{:?}", event).contains("Pressed") {
+                    let muted = app.state::<HotkeysState>().muted.load(Ordering::SeqCst);
+                    if let Err(e) = set_hotkeys_muted(app.clone(), app.state::<HotkeysState>(), !muted) {
+                        tracing::error!("❌ Hotkey mute toggle failed: {}", e);
                     }
                 }
-            }).map_err(|e| {
-                tracing::error!("❌ F11 register failed: {}", e);
-                e
-            })?;
+            }).unwrap_or_else(|e| {
+                tracing::error!("❌ {} register failed: {}", MUTE_TOGGLE_HOTKEY, e);
+                conflicts.push((MUTE_TOGGLE_HOTKEY.to_string(), e.to_string()));
+            });
+            record_hotkey_conflicts(app.handle(), conflicts);
 
             // TEXT EXPANSION: start global keyboard listener (rdev)
             EXPANSION_LISTENER_ONCE.call_once(|| {
@@ -132,9 +721,67 @@ fn main() {
             voice_to_text::paste_text,
             voice_to_text::set_recording_state,
             hotkeys::get_vtt_hotkey,
+            vtt_config::get_vtt_config,
+            vtt_config::set_vtt_language,
+            vtt_config::set_vtt_model,
+            vtt_config::set_vtt_mode,
             screenshot_new::launch_screenshot_overlay,  // LEGACY F8 (deprecated)
             screenshot_new::launch_screenshot_overlay_active_monitor,  // NEW F10
-            screenshot_new::launch_screenshot_overlay_all_monitors     // NEW F11
+            screenshot_new::launch_screenshot_overlay_all_monitors,    // NEW F11
+            screenshot_new::launch_screenshot_overlay_live,
+            screenshot_new::launch_screenshot_overlay_delayed,
+            screenshot_new::launch_screenshot_window_under_cursor,
+            screenshot_new::launch_color_picker,
+            screenshot_new::get_overlay_selection,
+            screenshot_new::list_monitors,
+            screenshot_new::capture_monitor_by_id,
+            screenshot_new::launch_screenshot_overlay_monitor,
+            screenshot_new::resolve_point,
+            screenshot_config::get_screenshot_config,
+            screenshot_config::set_rounded_corners,
+            screenshot_config::set_pagination,
+            screenshot_config::save_preset,
+            screenshot_config::list_presets,
+            screenshot_config::delete_preset,
+            screenshot_config::set_post_capture_preset,
+            screenshot_config::set_monitor_scale_override,
+            screenshot_config::get_screenshot_output,
+            screenshot_config::set_screenshot_output_dir,
+            screenshot_config::set_filename_template,
+            screenshot_config::set_max_output_dimension,
+            screenshot_config::set_screenshot_format,
+            screenshot_config::set_guide_color,
+            screenshot_config::set_auto_crop,
+            screenshot_new::autocrop_image,
+            screenshot_new::capture_virtual_desktop_to_clipboard,
+            screenshot_new::capture_active_window,
+            screenshot_new::capture_region,
+            screenshot_new::capture_full_monitor,
+            screenshot_config::set_window_capture_shadow,
+            screenshot_config::set_capture_sound,
+            screenshot_config::set_share_target,
+            screenshot_config::set_remember_last_monitor,
+            screenshot_config::set_selection_size_limits,
+            screenshot_config::set_default_action,
+            screenshot_config::set_post_capture_action,
+            screenshot_config::set_reuse_last_selection,
+            screenshot_new::share_screenshot,
+            set_hotkeys_muted,
+            get_hotkeys_muted,
+            set_vtt_hotkey,
+            set_screenshot_active_hotkey,
+            set_screenshot_all_hotkey,
+            set_expansion_pause_hotkey,
+            set_screenshot_monitor_hotkey,
+            set_desktop_clipboard_hotkey,
+            set_expansion_paused,
+            is_expansion_paused,
+            list_hotkey_conflicts,
+            capabilities::get_capabilities,
+            settings::reset_settings,
+            hotkey_probe::identify_hotkey_owner,
+            ocr::ocr_region,
+            ocr::set_ocr_language
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,49 @@
+use tauri::{AppHandle, Emitter};
+
+use crate::{ocr_engine::recognize, screenshot_config, screenshot_new};
+
+/// Whether an OCR language pack is installed for the BCP-47 tag
+#[cfg(windows)]
+fn language_supported(tag: &str) -> std::result::Result<bool, String> {
+    use windows::{core::HSTRING, Globalization::Language, Media::Ocr::OcrEngine};
+
+    let language = Language::CreateLanguage(&HSTRING::from(tag))
+        .map_err(|e| format!("Invalid language tag '{}': {}", tag, e))?;
+    OcrEngine::IsLanguageSupported(&language).map_err(|e| e.to_string())
+}
+
+#[cfg(not(windows))]
+fn language_supported(_tag: &str) -> std::result::Result<bool, String> {
+    Err("OCR is only available on Windows".into())
+}
+
+/// OCR an absolute region (x,y,w,h) of the virtual desktop, copy the text and emit `ocr-result`
+#[tauri::command]
+pub async fn ocr_region(app: AppHandle, x: i32, y: i32, w: i32, h: i32) -> std::result::Result<String, String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let img = screenshot_new::stitch_region(x, y, w, h).map_err(|e| format!("Capture failed: {}", e))?;
+    let config = screenshot_config::load_config(&app);
+    let text = recognize(img.as_raw(), img.width(), img.height(), config.ocr_language.as_deref())?;
+
+    app.clipboard()
+        .write_text(text.clone())
+        .map_err(|e| format!("Failed to write clipboard: {}", e))?;
+
+    tracing::info!("🔤 OCR recognized {} chars from {}×{} region", text.chars().count(), w, h);
+    let _ = app.emit("ocr-result", &text);
+    Ok(text)
+}
+
+/// Override the OCR language (BCP-47, e.g. "pl-PL"); None = system locale
+#[tauri::command]
+pub fn set_ocr_language(app: AppHandle, language: Option<String>) -> std::result::Result<(), String> {
+    if let Some(tag) = &language {
+        if !language_supported(tag)? {
+            return Err(format!("No OCR language pack installed for '{}'", tag));
+        }
+    }
+    let mut config = screenshot_config::load_config(&app);
+    config.ocr_language = language;
+    screenshot_config::save_config(&app, &config)
+}
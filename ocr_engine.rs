@@ -0,0 +1,103 @@
+// Also compiled into overlay_egui via #[path] - keep this file free of tauri/crate imports
+
+/// One recognized word with its box in image pixels
+// Only the overlay's pin text mode reads word boxes; the app OCRs to plain text
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct OcrWord {
+    pub text: String,
+    /// Index of the OCR line the word belongs to (words come in reading order)
+    pub line: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Recognize text in an RGBA buffer with Windows.Media.Ocr.
+/// `language` is a BCP-47 tag (e.g. "en-US"); None = the user's profile languages (system locale).
+#[cfg(windows)]
+pub fn recognize(rgba: &[u8], width: u32, height: u32, language: Option<&str>) -> std::result::Result<String, String> {
+    let result = run(rgba, width, height, language)?;
+    result.Text().map(|text| text.to_string()).map_err(|e| format!("OCR failed: {}", e))
+}
+
+/// Like `recognize`, but word by word with bounding boxes (same engine, so the same accuracy:
+/// small, low-contrast or stylized text may be split, merged or misread)
+#[cfg(windows)]
+#[allow(dead_code)]
+pub fn recognize_words(rgba: &[u8], width: u32, height: u32, language: Option<&str>) -> std::result::Result<Vec<OcrWord>, String> {
+    let result = run(rgba, width, height, language)?;
+    let collect = || -> windows::core::Result<Vec<OcrWord>> {
+        let mut words = Vec::new();
+        for (line, ocr_line) in result.Lines()?.into_iter().enumerate() {
+            for word in ocr_line.Words()? {
+                let rect = word.BoundingRect()?;
+                words.push(OcrWord {
+                    text: word.Text()?.to_string(),
+                    line,
+                    x: rect.X,
+                    y: rect.Y,
+                    width: rect.Width,
+                    height: rect.Height,
+                });
+            }
+        }
+        Ok(words)
+    };
+    collect().map_err(|e| format!("OCR failed: {}", e))
+}
+
+#[cfg(windows)]
+fn run(rgba: &[u8], width: u32, height: u32, language: Option<&str>) -> std::result::Result<windows::Media::Ocr::OcrResult, String> {
+    use windows::{
+        core::HSTRING,
+        Globalization::Language,
+        Graphics::Imaging::{BitmapAlphaMode, BitmapPixelFormat, SoftwareBitmap},
+        Media::Ocr::OcrEngine,
+        Storage::Streams::DataWriter,
+    };
+
+    let max = OcrEngine::MaxImageDimension().map_err(|e| e.to_string())?;
+    if width > max || height > max {
+        return Err(format!("Region {}×{} too large for OCR (max {}px per side)", width, height, max));
+    }
+
+    // SoftwareBitmap wants BGRA
+    let mut bgra = rgba.to_vec();
+    for px in bgra.chunks_exact_mut(4) {
+        px.swap(0, 2);
+    }
+
+    let run = || -> windows::core::Result<windows::Media::Ocr::OcrResult> {
+        let writer = DataWriter::new()?;
+        writer.WriteBytes(&bgra)?;
+        let buffer = writer.DetachBuffer()?;
+        let bitmap = SoftwareBitmap::CreateCopyWithAlphaFromBuffer(
+            &buffer,
+            BitmapPixelFormat::Bgra8,
+            width as i32,
+            height as i32,
+            BitmapAlphaMode::Premultiplied,
+        )?;
+
+        let engine = match language {
+            Some(tag) => OcrEngine::TryCreateFromLanguage(&Language::CreateLanguage(&HSTRING::from(tag))?)?,
+            None => OcrEngine::TryCreateFromUserProfileLanguages()?,
+        };
+        engine.RecognizeAsync(&bitmap)?.get()
+    };
+
+    run().map_err(|e| format!("OCR failed: {}", e))
+}
+
+#[cfg(not(windows))]
+pub fn recognize(_rgba: &[u8], _width: u32, _height: u32, _language: Option<&str>) -> std::result::Result<String, String> {
+    Err("OCR is only available on Windows".into())
+}
+
+#[cfg(not(windows))]
+#[allow(dead_code)]
+pub fn recognize_words(_rgba: &[u8], _width: u32, _height: u32, _language: Option<&str>) -> std::result::Result<Vec<OcrWord>, String> {
+    Err("OCR is only available on Windows".into())
+}
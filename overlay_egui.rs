@@ -30,12 +30,36 @@
 
 use eframe::egui;
 use xcap::{Monitor, image}; // xcap re-exports image crate
+
+// Shared with the app: debounced, non-blocking shutter sound
+#[path = "capture_sound.rs"]
+mod capture_sound;
+// Shared with the app: Windows.Media.Ocr recognition (T)
+#[path = "ocr_engine.rs"]
+mod ocr_engine;
+// Shared with the app: image post-processing (built against xcap's `image`, imported above)
+#[path = "postprocess.rs"]
+mod postprocess;
+
+#[path = "region.rs"]
+mod region;
+// Shared with the app: the screenshot-config.json schema, output naming and post-capture pipeline
+#[path = "screenshot_settings.rs"]
+mod screenshot_settings;
+use screenshot_settings::{ScreenshotConfig, SelectionAction};
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 use std::time::{Duration, Instant};
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+
+/// Border/hint color of a drag that is still below the minimum selection size
+const TOO_SMALL_RGB: [u8; 3] = [239, 68, 68];
 
-const MIN_SELECTION_SIZE: f32 = 5.0;
+/// Full-width/height alignment lines through the cursor (or the selection edges while dragging)
+const CROSSHAIR_RGB: [u8; 3] = [59, 130, 246];
+const CROSSHAIR_ALPHA: u8 = 140;
+const CROSSHAIR_WIDTH: f32 = 1.0;
 
 /// Monitor metadata (serializable for IPC)
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
@@ -49,8 +73,581 @@ struct CapturedMonitor {
     screen_index: usize,
 }
 
+/// How the selection border color is chosen
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+enum BorderMode {
+    /// Fixed accent blue
+    #[default]
+    Fixed,
+    /// Black or white per edge segment, whichever contrasts more with the pixels underneath
+    AutoContrast,
+}
+
+/// Annotation tool picked in the toolbar (None in SharedState = selection mode)
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AnnotationTool {
+    Arrow,
+    Rect,
+    Highlight,
+    Blur,
+    Pixelate,
+}
+
+/// Markup drawn over the selection, in virtual desktop coordinates
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Annotation {
+    Arrow { from: [f32; 2], to: [f32; 2] },
+    Rect { rect: [f32; 4] },
+    /// Semi-transparent marker fill
+    Highlight { rect: [f32; 4] },
+    /// Redaction: gaussian blur of the area
+    Blur { rect: [f32; 4] },
+    /// Redaction: mosaic (downsample + nearest upsample) of the area
+    Pixelate { rect: [f32; 4] },
+}
+
+const ANNOTATION_COLOR: egui::Color32 = egui::Color32::from_rgb(239, 68, 68);
+/// Stroke width in virtual pixels (scaled to output pixels when baking)
+const ANNOTATION_WIDTH: f32 = 3.0;
+const ARROW_HEAD_LEN: f32 = 16.0;
+/// Marker yellow, ~35% opacity
+const HIGHLIGHT_RGBA: [u8; 4] = [250, 204, 21, 90];
+/// Redaction strength in virtual pixels (scaled to output pixels when baking)
+const BLUR_SIGMA: f32 = 8.0;
+const PIXELATE_BLOCK: f32 = 12.0;
+/// Undo steps kept per session (each step stores the whole annotation list)
+const ANNOTATION_HISTORY_DEPTH: usize = 50;
+
+/// Annotation being moved with the Select tool
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct AnnotationGrab {
+    /// Index into SharedState::annotations
+    index: usize,
+    /// Press position in virtual desktop coordinates [x, y]
+    start: [f32; 2],
+    /// The annotation before the move (pushed to the undo history on release)
+    original: Annotation,
+}
+
+impl Annotation {
+    fn new(tool: AnnotationTool, start: egui::Pos2, end: egui::Pos2) -> Self {
+        let rect = egui::Rect::from_two_pos(start, end);
+        let rect = [rect.min.x, rect.min.y, rect.max.x, rect.max.y];
+        match tool {
+            AnnotationTool::Arrow => Annotation::Arrow { from: [start.x, start.y], to: [end.x, end.y] },
+            AnnotationTool::Rect => Annotation::Rect { rect },
+            AnnotationTool::Highlight => Annotation::Highlight { rect },
+            AnnotationTool::Blur => Annotation::Blur { rect },
+            AnnotationTool::Pixelate => Annotation::Pixelate { rect },
+        }
+    }
+
+    /// Skip accidental clicks that would leave a dot
+    fn is_meaningful(&self) -> bool {
+        match self {
+            Annotation::Arrow { from, to } => (to[0] - from[0]).hypot(to[1] - from[1]) >= 3.0,
+            Annotation::Rect { rect }
+            | Annotation::Highlight { rect }
+            | Annotation::Blur { rect }
+            | Annotation::Pixelate { rect } => {
+                rect[2] - rect[0] >= 3.0 && rect[3] - rect[1] >= 3.0
+            }
+        }
+    }
+
+    /// Whether a virtual point grabs this annotation: near the arrow or rectangle outline,
+    /// anywhere inside highlights and redactions
+    fn hit(&self, p: egui::Pos2) -> bool {
+        let slop = ANNOTATION_WIDTH + 4.0;
+        let to_rect = |r: &[f32; 4]| egui::Rect::from_min_max(egui::pos2(r[0], r[1]), egui::pos2(r[2], r[3]));
+        match self {
+            Annotation::Arrow { from, to } => {
+                let (a, b) = (egui::pos2(from[0], from[1]), egui::pos2(to[0], to[1]));
+                let ab = b - a;
+                let t = ((p - a).dot(ab) / ab.length_sq().max(f32::EPSILON)).clamp(0.0, 1.0);
+                (p - (a + ab * t)).length() <= slop
+            }
+            Annotation::Rect { rect } => {
+                let outline = to_rect(rect);
+                outline.expand(slop).contains(p) && !outline.shrink(slop).contains(p)
+            }
+            Annotation::Highlight { rect }
+            | Annotation::Blur { rect }
+            | Annotation::Pixelate { rect } => to_rect(rect).contains(p),
+        }
+    }
+
+    /// Copy shifted by `delta` virtual pixels
+    fn translated(&self, delta: egui::Vec2) -> Self {
+        let mut moved = self.clone();
+        match &mut moved {
+            Annotation::Arrow { from, to } => {
+                for point in [from, to] {
+                    point[0] += delta.x;
+                    point[1] += delta.y;
+                }
+            }
+            Annotation::Rect { rect }
+            | Annotation::Highlight { rect }
+            | Annotation::Blur { rect }
+            | Annotation::Pixelate { rect } => {
+                rect[0] += delta.x;
+                rect[1] += delta.y;
+                rect[2] += delta.x;
+                rect[3] += delta.y;
+            }
+        }
+        moved
+    }
+}
+
+/// The two barb end points of an arrow head at `to`
+fn arrow_head(from: egui::Pos2, to: egui::Pos2, len: f32) -> [egui::Pos2; 2] {
+    let dir = (to - from).normalized();
+    let barb = |angle: f32| to - egui::Vec2::angled(dir.angle() + angle) * len;
+    [barb(0.45), barb(-0.45)]
+}
+
+/// Paint a thick line segment into the image (distance-to-segment test per pixel)
+fn draw_segment(img: &mut image::RgbaImage, a: egui::Pos2, b: egui::Pos2, width: f32, color: image::Rgba<u8>) {
+    let half = width / 2.0;
+    let min_x = (a.x.min(b.x) - half).floor().max(0.0) as u32;
+    let min_y = (a.y.min(b.y) - half).floor().max(0.0) as u32;
+    let max_x = ((a.x.max(b.x) + half).ceil().max(0.0) as u32).min(img.width());
+    let max_y = ((a.y.max(b.y) + half).ceil().max(0.0) as u32).min(img.height());
+
+    let ab = b - a;
+    let len_sq = ab.length_sq().max(f32::EPSILON);
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let p = egui::pos2(x as f32 + 0.5, y as f32 + 0.5);
+            let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+            if (p - (a + ab * t)).length() <= half {
+                img.put_pixel(x, y, color);
+            }
+        }
+    }
+}
+
+/// Image-pixel region (x, y, w, h) of a virtual rect, clamped to the image (None if empty)
+fn clamp_region(img: &image::RgbaImage, min: egui::Pos2, max: egui::Pos2) -> Option<(u32, u32, u32, u32)> {
+    let x = (min.x.max(0.0) as u32).min(img.width());
+    let y = (min.y.max(0.0) as u32).min(img.height());
+    let x2 = (max.x.max(0.0).round() as u32).min(img.width());
+    let y2 = (max.y.max(0.0).round() as u32).min(img.height());
+    (x2 > x && y2 > y).then_some((x, y, x2 - x, y2 - y))
+}
+
+/// Burn annotations into a composed selection whose top-left is `origin` (virtual coords)
+fn bake_annotations(img: &mut image::RgbaImage, annotations: &[Annotation], origin: egui::Pos2, scale: f32) {
+    let to_img = |p: [f32; 2]| egui::pos2((p[0] - origin.x) * scale, (p[1] - origin.y) * scale);
+    let width = ANNOTATION_WIDTH * scale;
+    let [r, g, b, _] = ANNOTATION_COLOR.to_array();
+    let color = image::Rgba([r, g, b, 255]);
+
+    for annotation in annotations {
+        match annotation {
+            Annotation::Arrow { from, to } => {
+                let (from, to) = (to_img(*from), to_img(*to));
+                draw_segment(img, from, to, width, color);
+                for barb in arrow_head(from, to, ARROW_HEAD_LEN * scale) {
+                    draw_segment(img, to, barb, width, color);
+                }
+            }
+            Annotation::Rect { rect } => {
+                let (min, max) = (to_img([rect[0], rect[1]]), to_img([rect[2], rect[3]]));
+                let corners = [min, egui::pos2(max.x, min.y), max, egui::pos2(min.x, max.y)];
+                for i in 0..4 {
+                    draw_segment(img, corners[i], corners[(i + 1) % 4], width, color);
+                }
+            }
+            Annotation::Highlight { rect } => {
+                let (min, max) = (to_img([rect[0], rect[1]]), to_img([rect[2], rect[3]]));
+                let alpha = HIGHLIGHT_RGBA[3] as f32 / 255.0;
+                let x_range = (min.x.max(0.0) as u32)..(max.x.max(0.0) as u32).min(img.width());
+                for y in (min.y.max(0.0) as u32)..(max.y.max(0.0) as u32).min(img.height()) {
+                    for x in x_range.clone() {
+                        let px = img.get_pixel_mut(x, y);
+                        for c in 0..3 {
+                            px[c] = (px[c] as f32 * (1.0 - alpha) + HIGHLIGHT_RGBA[c] as f32 * alpha).round() as u8;
+                        }
+                    }
+                }
+            }
+            Annotation::Blur { rect } => {
+                let (min, max) = (to_img([rect[0], rect[1]]), to_img([rect[2], rect[3]]));
+                if let Some((x, y, w, h)) = clamp_region(img, min, max) {
+                    let area = image::imageops::crop_imm(img, x, y, w, h).to_image();
+                    let blurred = image::imageops::blur(&area, BLUR_SIGMA * scale);
+                    image::imageops::replace(img, &blurred, x as i64, y as i64);
+                }
+            }
+            Annotation::Pixelate { rect } => {
+                let (min, max) = (to_img([rect[0], rect[1]]), to_img([rect[2], rect[3]]));
+                if let Some((x, y, w, h)) = clamp_region(img, min, max) {
+                    let block = (PIXELATE_BLOCK * scale).max(1.0);
+                    let small_w = ((w as f32 / block).ceil() as u32).max(1);
+                    let small_h = ((h as f32 / block).ceil() as u32).max(1);
+                    let area = image::imageops::crop_imm(img, x, y, w, h).to_image();
+                    let small = image::imageops::resize(&area, small_w, small_h, image::imageops::FilterType::Triangle);
+                    let mosaic = image::imageops::resize(&small, w, h, image::imageops::FilterType::Nearest);
+                    image::imageops::replace(img, &mosaic, x as i64, y as i64);
+                }
+            }
+        }
+    }
+}
+
+/// Shutter sound still playing in this child - joined after the window closes, since the process
+/// exits right after saving and would otherwise cut it off
+static PENDING_SOUND: std::sync::Mutex<Option<std::thread::JoinHandle<()>>> = std::sync::Mutex::new(None);
+
+/// Scrolling (long) screenshots: the selection is re-captured while the user scrolls the content
+/// underneath, and each frame is stitched on by finding its vertical overlap with the previous one
+mod scroll_capture {
+    use eframe::egui;
+    use xcap::image::RgbaImage;
+    use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+    use std::thread::JoinHandle;
+    use std::time::Duration;
+
+    /// Time between frames - short enough that one wheel notch never scrolls a whole region away
+    const FRAME_INTERVAL: Duration = Duration::from_millis(200);
+    /// Time for all overlay processes to pick up `scrolling` and go transparent
+    const SETTLE_DELAY: Duration = Duration::from_millis(400);
+    /// Grayscale samples taken from each row for the overlap search
+    const SAMPLE_COLUMNS: u32 = 48;
+    /// Overlaps shorter than this are too ambiguous to trust
+    const MIN_OVERLAP_ROWS: u32 = 24;
+    /// Mean per-sample difference (0-255) still counted as the same content
+    const MATCH_THRESHOLD: f32 = 6.0;
+
+    /// Evenly spaced grayscale samples of every row
+    fn row_profiles(frame: &RgbaImage) -> Vec<Vec<u8>> {
+        let (width, height) = frame.dimensions();
+        let columns = SAMPLE_COLUMNS.min(width).max(1);
+        (0..height)
+            .map(|y| {
+                (0..columns)
+                    .map(|c| {
+                        let [r, g, b, _] = frame.get_pixel(c * width / columns, y).0;
+                        ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn row_diff(a: &[u8], b: &[u8]) -> f32 {
+        let total: u32 = a.iter().zip(b).map(|(x, y)| x.abs_diff(*y) as u32).sum();
+        total as f32 / a.len().max(1) as f32
+    }
+
+    /// Rows the content moved up between two frames of the same region.
+    /// Some(0) = nothing moved; None = no trustworthy overlap (scrolled past a whole frame,
+    /// scrolled up, or the content changed).
+    pub fn find_scroll_offset(prev: &RgbaImage, next: &RgbaImage) -> Option<u32> {
+        // Frames shorter than the probe band have no offset with a trustworthy overlap
+        // (height 0 would also divide by zero below)
+        if prev.dimensions() != next.dimensions() || prev.height() < MIN_OVERLAP_ROWS {
+            return None;
+        }
+        let height = prev.height();
+        let (prev_rows, next_rows) = (row_profiles(prev), row_profiles(next));
+
+        let mut best: Option<(u32, f32)> = None;
+        for offset in 0..=height.saturating_sub(MIN_OVERLAP_ROWS) {
+            // prev rows offset.. line up with next rows 0..; every 2nd row is enough to decide
+            let overlap = (height - offset) as usize;
+            let score = (0..overlap)
+                .step_by(2)
+                .map(|row| row_diff(&prev_rows[row + offset as usize], &next_rows[row]))
+                .sum::<f32>()
+                / overlap.div_ceil(2) as f32;
+
+            if best.is_some_and(|(_, best_score)| best_score <= score) {
+                continue;
+            }
+            best = Some((offset, score));
+            // Exact match: the smallest offset wins (keeps blank areas from matching anywhere)
+            if score == 0.0 {
+                break;
+            }
+        }
+        best.filter(|(_, score)| *score <= MATCH_THRESHOLD).map(|(offset, _)| offset)
+    }
+
+    /// Tall image grown frame by frame
+    pub struct Stitcher {
+        image: RgbaImage,
+        last: RgbaImage,
+        frames: u32,
+    }
+
+    impl Stitcher {
+        pub fn new(first: RgbaImage) -> Self {
+            Self { image: first.clone(), last: first, frames: 1 }
+        }
+
+        /// Append the rows that scrolled into view; returns how many were added.
+        /// Frames without a usable overlap are dropped and the next one is compared to the last good frame,
+        /// so scrolling back a little after a too-fast flick recovers.
+        pub fn push(&mut self, frame: RgbaImage) -> u32 {
+            match find_scroll_offset(&self.last, &frame) {
+                Some(0) => 0,
+                Some(offset) => {
+                    let (width, height) = frame.dimensions();
+                    let new_height = self.image.height() + offset;
+                    let first_new_row = ((height - offset) * width * 4) as usize;
+                    let mut raw = std::mem::take(&mut self.image).into_raw();
+                    raw.extend_from_slice(&frame.as_raw()[first_new_row..]);
+                    self.image = RgbaImage::from_raw(width, new_height, raw)
+                        .expect("stitched buffer matches its dimensions");
+                    self.last = frame;
+                    self.frames += 1;
+                    offset
+                }
+                None => {
+                    tracing::warn!("📜 No overlap with the previous frame - skipped (scrolled too fast?)");
+                    0
+                }
+            }
+        }
+
+        /// False when the content never moved (nothing to stitch)
+        pub fn moved(&self) -> bool {
+            self.frames > 1
+        }
+
+        pub fn frames(&self) -> u32 {
+            self.frames
+        }
+
+        pub fn finish(self) -> RgbaImage {
+            self.image
+        }
+    }
+
+    /// Enter/Escape pressed anywhere - the overlay is click-through while scrolling, so egui never sees the keys
+    #[cfg(windows)]
+    fn key_down(vk: windows::Win32::UI::Input::KeyboardAndMouse::VIRTUAL_KEY) -> bool {
+        use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+        // High bit = key currently down
+        unsafe { GetAsyncKeyState(vk.0 as i32) as u16 & 0x8000 != 0 }
+    }
+
+    #[cfg(windows)]
+    fn finish_pressed() -> bool {
+        key_down(windows::Win32::UI::Input::KeyboardAndMouse::VK_RETURN)
+    }
+
+    #[cfg(windows)]
+    fn cancel_pressed() -> bool {
+        key_down(windows::Win32::UI::Input::KeyboardAndMouse::VK_ESCAPE)
+    }
+
+    #[cfg(not(windows))]
+    fn finish_pressed() -> bool {
+        false
+    }
+
+    #[cfg(not(windows))]
+    fn cancel_pressed() -> bool {
+        false
+    }
+
+    /// Background capture loop for one scroll capture
+    pub struct Job {
+        finish: Arc<AtomicBool>,
+        handle: JoinHandle<Option<Stitcher>>,
+    }
+
+    impl Job {
+        pub fn start<F>(capture: F, ctx: egui::Context) -> Self
+        where
+            F: Fn() -> Result<RgbaImage, String> + Send + 'static,
+        {
+            let finish = Arc::new(AtomicBool::new(false));
+            let finish_flag = finish.clone();
+            let handle = std::thread::spawn(move || {
+                let outcome = run(&capture, &finish_flag);
+                ctx.request_repaint();
+                outcome
+            });
+            Self { finish, handle }
+        }
+
+        /// Stop after one more frame (Enter seen by egui)
+        pub fn finish(&self) {
+            self.finish.store(true, Ordering::SeqCst);
+        }
+
+        pub fn is_done(&self) -> bool {
+            self.handle.is_finished()
+        }
+
+        /// None = cancelled with Escape or the first frame could not be captured
+        pub fn join(self) -> Option<Stitcher> {
+            self.handle.join().unwrap_or_else(|_| {
+                tracing::error!("📜 Scroll capture thread panicked");
+                None
+            })
+        }
+    }
+
+    fn run(capture: &dyn Fn() -> Result<RgbaImage, String>, finish: &AtomicBool) -> Option<Stitcher> {
+        // Let every overlay window repaint transparent first, or the frozen frame ends up in frame 1
+        std::thread::sleep(SETTLE_DELAY);
+        let mut stitcher = match capture() {
+            Ok(first) => Stitcher::new(first),
+            Err(e) => {
+                tracing::error!("📜 Scroll capture failed to grab the first frame: {}", e);
+                return None;
+            }
+        };
+
+        loop {
+            std::thread::sleep(FRAME_INTERVAL);
+            if cancel_pressed() {
+                tracing::info!("📜 Scroll capture cancelled");
+                return None;
+            }
+            let finishing = finish.load(Ordering::SeqCst) || finish_pressed();
+
+            match capture() {
+                Ok(frame) => {
+                    let added = stitcher.push(frame);
+                    if added > 0 {
+                        tracing::info!("📜 Frame {}: +{} rows", stitcher.frames(), added);
+                    }
+                }
+                Err(e) => tracing::warn!("📜 Frame capture failed: {}", e),
+            }
+
+            if finishing {
+                return Some(stitcher);
+            }
+        }
+    }
+}
+
+/// #RRGGBB for the clipboard and the picker label
+fn color_hex(color: egui::Color32) -> String {
+    format!("#{:02X}{:02X}{:02X}", color.r(), color.g(), color.b())
+}
+
+/// Composition guide drawn over every monitor (visual only, never constrains the selection)
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+enum GuideKind {
+    #[default]
+    Off,
+    /// Rule-of-thirds grid
+    Thirds,
+    /// Action-safe (90%) and title-safe (80%) frames
+    TitleSafe,
+    /// Largest centered 16:9 box
+    Aspect16x9,
+    /// Largest centered 1:1 box
+    Aspect1x1,
+}
+
+impl GuideKind {
+    fn next(self) -> Self {
+        match self {
+            GuideKind::Off => GuideKind::Thirds,
+            GuideKind::Thirds => GuideKind::TitleSafe,
+            GuideKind::TitleSafe => GuideKind::Aspect16x9,
+            GuideKind::Aspect16x9 => GuideKind::Aspect1x1,
+            GuideKind::Aspect1x1 => GuideKind::Off,
+        }
+    }
+}
+
+/// Aspect ratios cycled with A (followed by free)
+const ASPECT_RATIOS: [(u32, u32); 3] = [(1, 1), (4, 3), (16, 9)];
+
+fn next_aspect_lock(current: Option<(u32, u32)>) -> Option<(u32, u32)> {
+    match current {
+        None => Some(ASPECT_RATIOS[0]),
+        Some(ratio) => ASPECT_RATIOS.iter()
+            .position(|r| *r == ratio)
+            .and_then(|i| ASPECT_RATIOS.get(i + 1).copied()),
+    }
+}
+
+/// Move `current` so the start→current rectangle has the given ratio, keeping `start` anchored
+/// and the drag direction intact (the shorter side wins, so the box never outgrows the cursor)
+fn constrain_to_aspect(start: egui::Pos2, current: egui::Pos2, (ratio_w, ratio_h): (u32, u32)) -> egui::Pos2 {
+    let ratio = ratio_w as f32 / ratio_h as f32;
+    let dx = current.x - start.x;
+    let dy = current.y - start.y;
+
+    let (mut width, mut height) = (dx.abs(), dy.abs());
+    if height * ratio < width {
+        width = height * ratio;
+    } else {
+        height = width / ratio;
+    }
+
+    egui::pos2(start.x + width.copysign(dx), start.y + height.copysign(dy))
+}
+
+/// How an overlay session ended (None in SharedState = still open, or cancelled with Escape)
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum OverlayAction {
+    Saved,
+    Copied,
+    /// OCR text copied
+    Text,
+    Pinned,
+    /// Picker color copied
+    Color,
+}
+
+impl SelectionAction {
+    /// Parse `--on-release save_file|copy_clipboard|stay_open` (inverse of as_arg)
+    fn from_arg(value: &str) -> Result<Self, String> {
+        match value {
+            "save_file" => Ok(Self::SaveFile),
+            "copy_clipboard" => Ok(Self::CopyClipboard),
+            "stay_open" => Ok(Self::StayOpen),
+            _ => Err(format!("--on-release expects save_file, copy_clipboard or stay_open, got '{}'", value)),
+        }
+    }
+}
+
+/// Written to `--result <path>` by the parent once every child has exited
+#[derive(serde::Serialize)]
+struct OverlayResult {
+    selection: Option<[f32; 4]>,
+    saved_path: Option<PathBuf>,
+    action: Option<OverlayAction>,
+    saved_pages: Vec<PathBuf>,
+    text: Option<String>,
+    copied_size: Option<[u32; 2]>,
+    cancelled: bool,
+}
+
+impl OverlayResult {
+    fn from_state(state: &SharedState) -> Self {
+        Self {
+            selection: state.selection_rect,
+            saved_path: state.saved_path.clone(),
+            action: state.action,
+            saved_pages: state.saved_pages.clone(),
+            text: state.text.clone(),
+            copied_size: state.copied_size,
+            cancelled: state.action.is_none(),
+        }
+    }
+}
+
 /// Shared state synchronized across processes via file
-#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 struct SharedState {
     /// Selection rectangle in virtual desktop coordinates [min_x, min_y, max_x, max_y]
     selection_rect: Option<[f32; 4]>,
@@ -60,6 +657,80 @@ struct SharedState {
     drag_start: Option<[f32; 2]>,
     /// Whether to close all windows
     should_close: bool,
+    /// Whether the dark dimming layer is drawn (toggled with D)
+    dim_enabled: bool,
+    /// Selection border coloring (toggled with B)
+    border_mode: BorderMode,
+    /// Composition guide (cycled with G)
+    guide: GuideKind,
+    /// Aspect ratio enforced while Shift is held during a drag (cycled with A; None = free)
+    aspect_lock: Option<(u32, u32)>,
+    /// False in live mode until F freezes the frame (selection is disabled until then)
+    frozen: bool,
+    /// Bumped whenever monitor_N.png files are re-captured; children reload their textures on change
+    frame_version: u32,
+    /// Color picker: a click copies the pixel's #RRGGBB instead of starting a selection (toggled with C)
+    picker_mode: bool,
+    /// Active annotation tool (None = dragging selects)
+    tool: Option<AnnotationTool>,
+    /// Committed annotations and redactions
+    annotations: Vec<Annotation>,
+    /// Annotation being dragged right now (rendered by every monitor)
+    draft: Option<Annotation>,
+    /// Annotation being moved with the Select tool
+    grab: Option<AnnotationGrab>,
+    /// `annotations` before each add/move/delete, newest last (Ctrl+Z restores; capped at ANNOTATION_HISTORY_DEPTH)
+    undo_stack: Vec<Vec<Annotation>>,
+    /// Lists replaced by Ctrl+Z (Ctrl+Shift+Z / Ctrl+Y brings them back); cleared by any new edit
+    redo_stack: Vec<Vec<Annotation>>,
+    /// Incremented on every write; a write based on an older version than the file is rejected
+    version: u64,
+    /// screen_index of the process that started the current drag - only it may write until release
+    drag_owner: Option<usize>,
+    /// What closed the session (set together with should_close; None = cancelled)
+    action: Option<OverlayAction>,
+    /// File written by Enter (first page when the capture was split)
+    saved_path: Option<PathBuf>,
+    /// Every page written when the capture was split into name_pNN files (empty = single file)
+    saved_pages: Vec<PathBuf>,
+    /// Text recognized by T (already on the clipboard)
+    text: Option<String>,
+    /// Pixel size of the image put on the clipboard by Ctrl+C
+    copied_size: Option<[u32; 2]>,
+    /// Scroll capture running (S): every window turns click-through and only outlines the selection
+    scrolling: bool,
+}
+
+impl Default for SharedState {
+    fn default() -> Self {
+        Self {
+            selection_rect: None,
+            is_dragging: false,
+            drag_start: None,
+            should_close: false,
+            dim_enabled: true,
+            border_mode: BorderMode::Fixed,
+            guide: GuideKind::Off,
+            aspect_lock: None,
+            frozen: true,
+            frame_version: 0,
+            picker_mode: false,
+            tool: None,
+            annotations: Vec::new(),
+            draft: None,
+            grab: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            version: 0,
+            drag_owner: None,
+            action: None,
+            saved_path: None,
+            saved_pages: Vec::new(),
+            text: None,
+            copied_size: None,
+            scrolling: false,
+        }
+    }
 }
 
 impl SharedState {
@@ -87,176 +758,484 @@ impl SharedState {
     fn set_drag_start(&mut self, pos: Option<egui::Pos2>) {
         self.drag_start = pos.map(|p| [p.x, p.y]);
     }
+
+    /// Record the annotation list as it was before an add/move/delete; a new edit drops the redo branch
+    fn push_history(&mut self, before: Vec<Annotation>) {
+        self.undo_stack.push(before);
+        if self.undo_stack.len() > ANNOTATION_HISTORY_DEPTH {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    /// Restore the list before the last edit; false when there is nothing to undo
+    fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo_stack.pop() else {
+            return false;
+        };
+        self.redo_stack.push(std::mem::replace(&mut self.annotations, previous));
+        true
+    }
+
+    /// Re-apply the last undone edit; false when there is nothing to redo
+    fn redo(&mut self) -> bool {
+        let Some(next) = self.redo_stack.pop() else {
+            return false;
+        };
+        self.undo_stack.push(std::mem::replace(&mut self.annotations, next));
+        true
+    }
+
+    /// Topmost annotation under a virtual point
+    fn annotation_at(&self, pos: egui::Pos2) -> Option<usize> {
+        self.annotations.iter().rposition(|annotation| annotation.hit(pos))
+    }
 }
 
-struct OverlayApp {
-    monitor: CapturedMonitor,
-    texture: Option<egui::TextureHandle>,
-    texture_width: u32,   // Actual texture width after GPU downscale
-    texture_height: u32,  // Actual texture height after GPU downscale
-    state_file: PathBuf,
-    virtual_desktop_bounds: egui::Rect,
-    local_cursor_pos: Option<egui::Pos2>,
-    last_state_check: Instant,
+/// GPU texture size limit (most GPUs max 2048×2048)
+const MAX_TEXTURE_SIZE: u32 = 2048;
+
+/// One ≤2048px piece of the screenshot, positioned in image pixels
+struct TextureTile {
+    texture: egui::TextureHandle,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
 }
 
-impl OverlayApp {
-    fn new(
-        cc: &eframe::CreationContext<'_>,
-        monitor: CapturedMonitor,
-        state_file: PathBuf,
-        virtual_desktop_bounds: egui::Rect,
-    ) -> Self {
-        // Load screenshot from PNG file
-        let texture = match image::open(&monitor.image_path) {
-            Ok(img) => {
-                let mut rgba = img.to_rgba8();
-
-                // Get logical dimensions (what egui expects)
-                let logical_width = monitor.width;
-                let logical_height = monitor.height;
-                let mut physical_width = rgba.width();
-                let mut physical_height = rgba.height();
-
-                // CRITICAL FIX: GPU texture size limit (most GPUs max 2048×2048)
-                const MAX_TEXTURE_SIZE: u32 = 2048;
-
-                if physical_width > MAX_TEXTURE_SIZE || physical_height > MAX_TEXTURE_SIZE {
-                    // Calculate scale to fit within GPU limits
-                    let scale = (MAX_TEXTURE_SIZE as f32 / physical_width.max(physical_height) as f32).min(1.0);
-                    let target_width = (physical_width as f32 * scale) as u32;
-                    let target_height = (physical_height as f32 * scale) as u32;
+/// Split a screenshot into a grid of ≤MAX_TEXTURE_SIZE textures (keeps 4K/ultrawide at full resolution)
+fn load_texture_tiles(ctx: &egui::Context, image: &egui::ColorImage, screen_index: usize) -> Vec<TextureTile> {
+    let [img_w, img_h] = image.size;
+    let tile_size = MAX_TEXTURE_SIZE as usize;
+    let mut tiles = Vec::new();
 
-                    tracing::warn!(
-                        "Monitor {}: Texture size {}×{} exceeds GPU limit ({}). Downscaling to {}×{}",
-                        monitor.screen_index,
-                        physical_width,
-                        physical_height,
-                        MAX_TEXTURE_SIZE,
-                        target_width,
-                        target_height
-                    );
+    for y in (0..img_h).step_by(tile_size) {
+        for x in (0..img_w).step_by(tile_size) {
+            let width = tile_size.min(img_w - x);
+            let height = tile_size.min(img_h - y);
 
-                    rgba = image::imageops::resize(
-                        &rgba,
-                        target_width,
-                        target_height,
-                        image::imageops::FilterType::Lanczos3,
-                    );
+            let mut pixels = Vec::with_capacity(width * height);
+            for row in y..y + height {
+                pixels.extend_from_slice(&image.pixels[row * img_w + x..row * img_w + x + width]);
+            }
 
-                    physical_width = target_width;
-                    physical_height = target_height;
-                }
+            let texture = ctx.load_texture(
+                format!("monitor_{}_tile_{}_{}", screen_index, x, y),
+                egui::ColorImage { size: [width, height], pixels },
+                egui::TextureOptions::LINEAR,
+            );
+            tiles.push(TextureTile { texture, x, y, width, height });
+        }
+    }
 
-                // CRITICAL: Final texture size MUST NOT exceed GPU limit
-                // Calculate final size preserving aspect ratio
-                let (final_width, final_height) = if logical_width > MAX_TEXTURE_SIZE || logical_height > MAX_TEXTURE_SIZE {
-                    // Scale down proportionally to fit within GPU limits
-                    let scale = (MAX_TEXTURE_SIZE as f32 / logical_width.max(logical_height) as f32).min(1.0);
-                    let scaled_width = (logical_width as f32 * scale) as u32;
-                    let scaled_height = (logical_height as f32 * scale) as u32;
+    tiles
+}
 
-                    tracing::info!(
-                        "Monitor {}: Scaling texture to fit GPU limit: {}×{} → {}×{} (scale: {:.2})",
-                        monitor.screen_index,
-                        logical_width,
-                        logical_height,
-                        scaled_width,
-                        scaled_height,
-                        scale
-                    );
+/// Diagnostics (debug box on every monitor) are opt-in via AP30D_OVERLAY_DEBUG=1
+fn debug_enabled() -> bool {
+    std::env::var("AP30D_OVERLAY_DEBUG").is_ok_and(|v| !v.is_empty() && v != "0")
+}
 
-                    (scaled_width, scaled_height)
-                } else {
-                    (logical_width, logical_height)
-                };
+/// PID of the parent overlay that owns a session directory (<pid>_<timestamp>), as in screenshot_new.rs
+fn session_owner(session_dir: &std::path::Path) -> Option<u32> {
+    session_dir.file_name()?.to_str()?.split('_').next()?.parse().ok()
+}
 
-                // Resize to final dimensions if needed
-                if physical_width != final_width || physical_height != final_height {
-                    tracing::info!(
-                        "Monitor {}: Resizing texture from physical {}×{} to final {}×{} (DPI scale: {:.2})",
-                        monitor.screen_index,
-                        physical_width,
-                        physical_height,
-                        final_width,
-                        final_height,
-                        monitor.scale_factor
-                    );
+/// Another overlay session is still open: the parent whose PID names the directory is running.
+/// A crashed run's leftovers go as soon as its parent is gone, however recently it wrote state.json.
+fn session_in_progress(session_dir: &std::path::Path) -> bool {
+    session_owner(session_dir).is_some_and(process_alive)
+}
 
-                    rgba = image::imageops::resize(
-                        &rgba,
-                        final_width,
-                        final_height,
-                        image::imageops::FilterType::Lanczos3, // High-quality downscaling
-                    );
-                }
+/// Whether a process with this PID is still running
+#[cfg(windows)]
+fn process_alive(pid: u32) -> bool {
+    use windows::Win32::{
+        Foundation::{CloseHandle, STILL_ACTIVE},
+        System::Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION},
+    };
 
-                // Convert to egui ColorImage with final size (guaranteed ≤ 2048)
-                let pixels: Vec<egui::Color32> = rgba.pixels().map(|p| {
-                    egui::Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3])
-                }).collect();
+    unsafe {
+        let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+            return false;
+        };
+        let mut exit_code = 0u32;
+        let alive = GetExitCodeProcess(handle, &mut exit_code).is_ok() && exit_code == STILL_ACTIVE.0 as u32;
+        let _ = CloseHandle(handle);
+        alive
+    }
+}
 
-                let color_image = egui::ColorImage {
-                    size: [final_width as usize, final_height as usize],
-                    pixels,
-                };
+#[cfg(not(windows))]
+fn process_alive(pid: u32) -> bool {
+    std::path::Path::new(&format!("/proc/{}", pid)).exists()
+}
 
-                Some(cc.egui_ctx.load_texture(
-                    format!("monitor_{}", monitor.screen_index),
-                    color_image,
-                    egui::TextureOptions::LINEAR
-                ))
-            }
+/// Hide every top-level window of this process from screen capture (WDA_EXCLUDEFROMCAPTURE),
+/// so re-captures (F freeze, scroll capture, capture fallbacks) never contain the overlay itself.
+/// Needs Windows 10 2004+; older systems keep capturing the windows. Returns how many were excluded.
+#[cfg(windows)]
+fn exclude_own_windows_from_capture() -> usize {
+    use windows::Win32::{
+        Foundation::{BOOL, HWND, LPARAM},
+        System::Threading::GetCurrentProcessId,
+        UI::WindowsAndMessaging::{EnumWindows, GetWindowThreadProcessId, SetWindowDisplayAffinity, WDA_EXCLUDEFROMCAPTURE},
+    };
+
+    unsafe extern "system" fn collect(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid as *mut u32));
+        if pid == GetCurrentProcessId() {
+            let windows = &mut *(lparam.0 as *mut Vec<HWND>);
+            windows.push(hwnd);
+        }
+        true.into()
+    }
+
+    let mut own_windows: Vec<HWND> = Vec::new();
+    unsafe {
+        if let Err(e) = EnumWindows(Some(collect), LPARAM(&mut own_windows as *mut Vec<HWND> as isize)) {
+            tracing::warn!("EnumWindows failed: {}", e);
+            return 0;
+        }
+    }
+
+    own_windows
+        .into_iter()
+        .filter(|&hwnd| match unsafe { SetWindowDisplayAffinity(hwnd, WDA_EXCLUDEFROMCAPTURE) } {
+            Ok(()) => true,
             Err(e) => {
-                tracing::error!("Failed to load screenshot from {}: {}",
-                    monitor.image_path.display(), e);
-                None
+                tracing::warn!("Failed to exclude window {:?} from capture: {}", hwnd, e);
+                false
             }
-        };
+        })
+        .count()
+}
+
+#[cfg(not(windows))]
+fn exclude_own_windows_from_capture() -> usize {
+    0
+}
+
+/// Single-instance lock held by the overlay parent for its whole session (removed on drop).
+/// The file holds the parent's PID, so a lock left behind by a crash is taken over once that PID is gone.
+struct InstanceLock {
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    /// Next to (not inside) egui_overlay, which gets deleted during cleanup.
+    /// screenshot_new.rs checks the same file before spawning.
+    fn path() -> PathBuf {
+        std::env::temp_dir().join("egui_overlay.lock")
+    }
+
+    /// None = another live overlay parent holds the lock
+    fn acquire() -> std::io::Result<Option<Self>> {
+        use std::io::Write;
+
+        let path = Self::path();
+        // Second attempt only after removing a stale lock
+        for _ in 0..2 {
+            match fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    write!(file, "{}", std::process::id())?;
+                    return Ok(Some(Self { path }));
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let holder = fs::read_to_string(&path).ok().and_then(|pid| pid.trim().parse::<u32>().ok());
+                    // No PID yet = the holder created the file a moment ago and is about to write it
+                    let just_created = fs::metadata(&path)
+                        .and_then(|m| m.modified())
+                        .is_ok_and(|t| t.elapsed().is_ok_and(|age| age < Duration::from_secs(2)));
+                    let alive = match holder {
+                        Some(pid) => process_alive(pid),
+                        None => just_created,
+                    };
+                    if alive {
+                        return Ok(None);
+                    }
+                    tracing::warn!("Removing stale overlay lock (PID {:?})", holder);
+                    match fs::remove_file(&path) {
+                        Ok(()) => {}
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// %TEMP%\egui_overlay - holds one directory per capture session
+fn overlay_root() -> PathBuf {
+    std::env::temp_dir().join("egui_overlay")
+}
+
+/// Delete session directories of crashed runs (plus loose files from the old flat layout).
+/// Sessions that still look alive are kept in case the instance lock could not be taken.
+fn remove_stale_sessions(overlay_root: &std::path::Path) {
+    if debug_enabled() {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(overlay_root) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if !session_in_progress(&path) {
+                remove_temp_dir(&path);
+            }
+        } else {
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
+
+/// Delete a session's monitor_*.png, *_RAW_PHYSICAL.png and the JSON files (kept when AP30D_OVERLAY_DEBUG is set)
+fn remove_temp_dir(temp_dir: &std::path::Path) {
+    if debug_enabled() || !temp_dir.exists() {
+        return;
+    }
+    match fs::remove_dir_all(temp_dir) {
+        Ok(()) => tracing::info!("Cleaned up temp directory {}", temp_dir.display()),
+        Err(e) => tracing::warn!("Failed to cleanup temp directory: {}", e),
+    }
+}
+
+/// How the overlay window is sized relative to its monitor
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum WindowGeometry {
+    /// Window covers the monitor's full logical size; the texture is stretched to fit
+    Monitor,
+    /// Legacy: window sized to the (possibly downscaled) texture, position scaled by the same ratio
+    Texture,
+}
+
+impl WindowGeometry {
+    /// Read from AP30D_OVERLAY_WINDOW_GEOMETRY ("texture" selects the legacy behavior for comparison)
+    fn from_env() -> Self {
+        match std::env::var("AP30D_OVERLAY_WINDOW_GEOMETRY").as_deref() {
+            Ok("texture") => WindowGeometry::Texture,
+            _ => WindowGeometry::Monitor,
+        }
+    }
+}
+
+struct OverlayApp {
+    monitor: CapturedMonitor,
+    all_monitors: Vec<CapturedMonitor>,  // Every captured monitor (selections may span several)
+    config: ScreenshotConfig,
+    tiles: Vec<TextureTile>,  // Full-resolution screenshot split into GPU-sized tiles
+    pixels: Option<egui::ColorImage>,  // CPU copy of the screenshot for pixel sampling
+    view_size: egui::Vec2,  // Window inner size in points (texture is painted to fill it)
+    debug: bool,  // Draw the monitor/texture debug box (AP30D_OVERLAY_DEBUG)
+    session_dir: PathBuf,  // This capture session's egui_overlay/<session> directory
+    on_release: SelectionAction,  // What finishing a selection drag does (--on-release)
+    state_file: PathBuf,
+    virtual_desktop_bounds: egui::Rect,
+    local_cursor_pos: Option<egui::Pos2>,
+    state_cache: std::cell::RefCell<Option<SharedState>>,  // Last known state.json contents
+    state_dirty: Arc<AtomicBool>,  // Set by the watcher when state.json changes on disk
+    state_watcher: Option<notify::RecommendedWatcher>,  // None → fall back to 100ms polling
+    loaded_frame: u32,  // SharedState::frame_version the textures were loaded from
+    scroll_job: Option<scroll_capture::Job>,  // Only in the process where S was pressed
+    passthrough: bool,  // Window currently click-through (scroll capture)
+}
+
+/// Decode an image file into egui pixels at full resolution
+fn load_color_image(path: &std::path::Path) -> image::ImageResult<egui::ColorImage> {
+    Ok(to_color_image(&image::open(path)?.to_rgba8()))
+}
+
+fn to_color_image(rgba: &image::RgbaImage) -> egui::ColorImage {
+    let pixels: Vec<egui::Color32> = rgba.pixels().map(|p| {
+        egui::Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3])
+    }).collect();
+
+    egui::ColorImage {
+        size: [rgba.width() as usize, rgba.height() as usize],
+        pixels,
+    }
+}
+
+/// Load the monitor's screenshot PNG at full resolution and split it into GPU-sized tiles
+fn load_screenshot(ctx: &egui::Context, monitor: &CapturedMonitor) -> (Option<egui::ColorImage>, Vec<TextureTile>) {
+    let pixels = match load_color_image(&monitor.image_path) {
+        Ok(image) => Some(image),
+        Err(e) => {
+            tracing::error!("Failed to load screenshot from {}: {}",
+                monitor.image_path.display(), e);
+            None
+        }
+    };
+
+    // GPU texture size limit (most GPUs max 2048×2048): split into tiles instead of downscaling
+    let tiles = pixels.as_ref()
+        .map(|image| load_texture_tiles(ctx, image, monitor.screen_index))
+        .unwrap_or_default();
+
+    (pixels, tiles)
+}
+
+impl OverlayApp {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        cc: &eframe::CreationContext<'_>,
+        monitor: CapturedMonitor,
+        all_monitors: Vec<CapturedMonitor>,
+        config: ScreenshotConfig,
+        session_dir: PathBuf,
+        on_release: SelectionAction,
+        virtual_desktop_bounds: egui::Rect,
+        view_size: egui::Vec2,
+    ) -> Self {
+        let (pixels, tiles) = load_screenshot(&cc.egui_ctx, &monitor);
 
         tracing::info!(
             "Child process: overlay window created for monitor {} at ({}, {}) size {}×{}",
             monitor.screen_index, monitor.x, monitor.y, monitor.width, monitor.height
         );
 
-        // Extract actual texture dimensions (after GPU downscale)
-        let (texture_width, texture_height) = if let Some(tex) = &texture {
-            let [w, h] = tex.size();
-            tracing::info!("📐 Texture actual size: {}×{} (may differ from monitor size due to GPU limits)", w, h);
-            (w as u32, h as u32)
+        if let Some(image) = &pixels {
+            tracing::info!(
+                "📐 Screenshot {}×{} loaded as {} tile(s), painted into {:.0}×{:.0} window",
+                image.size[0], image.size[1], tiles.len(), view_size.x, view_size.y
+            );
         } else {
-            tracing::warn!("⚠️ No texture loaded, using monitor dimensions as fallback");
-            (monitor.width, monitor.height)
-        };
+            tracing::warn!("⚠️ No texture loaded");
+        }
 
         // NOTE: Window is already created with correct size in run_monitor_overlay()
         // No need to resize here anymore
 
+        let state_file = session_dir.join("state.json");
+        let state_dirty = Arc::new(AtomicBool::new(true));
+        let state_watcher = watch_state_file(&state_file, cc.egui_ctx.clone(), state_dirty.clone());
+
         Self {
             monitor,
-            texture,
-            texture_width,
-            texture_height,
+            all_monitors,
+            config,
+            tiles,
+            pixels,
+            view_size,
+            debug: debug_enabled(),
+            session_dir,
+            on_release,
             state_file,
             virtual_desktop_bounds,
             local_cursor_pos: None,
-            last_state_check: Instant::now(),
+            state_cache: std::cell::RefCell::new(None),
+            state_dirty,
+            state_watcher,
+            loaded_frame: 0,
+            scroll_job: None,
+            passthrough: false,
         }
     }
 
-    /// Read shared state from file
+    /// Live mode: re-capture every monitor now and switch all overlays to the frozen frame.
+    ///
+    /// Capturing takes roughly 50-200ms per monitor, so the frozen frame lags the key press by
+    /// that much. The overlay windows are in the capture too, but in live mode they only draw
+    /// the ~1% input layer, which is not visible in the result.
+    fn freeze_frame(&mut self, ctx: &egui::Context, state: &mut SharedState) {
+        let started = Instant::now();
+        let monitors = capture_all_monitors(&self.config, &self.session_dir);
+        if monitors.is_empty() {
+            tracing::error!("Freeze failed: no monitors captured");
+            return;
+        }
+
+        self.write_state(state, |s| {
+            s.frozen = true;
+            s.frame_version += 1;
+        });
+        tracing::info!("🧊 Frame frozen in {}ms (version {})", started.elapsed().as_millis(), state.frame_version);
+
+        self.reload_screenshot(ctx, state.frame_version);
+    }
+
+    /// Reload textures after another process re-captured the monitor PNGs
+    fn reload_screenshot(&mut self, ctx: &egui::Context, frame_version: u32) {
+        let (pixels, tiles) = load_screenshot(ctx, &self.monitor);
+        self.pixels = pixels;
+        self.tiles = tiles;
+        self.loaded_frame = frame_version;
+    }
+
+    /// Read shared state (from file only when the watcher saw a change, otherwise cached)
     fn read_state(&self) -> SharedState {
-        fs::read_to_string(&self.state_file)
-            .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
-            .unwrap_or_default()
+        let mut cache = self.state_cache.borrow_mut();
+        if self.state_dirty.swap(false, Ordering::SeqCst) || cache.is_none() {
+            *cache = Some(
+                fs::read_to_string(&self.state_file)
+                    .ok()
+                    .and_then(|s| serde_json::from_str(&s).ok())
+                    .unwrap_or_default(),
+            );
+        }
+        cache.clone().unwrap_or_default()
     }
 
-    /// Write shared state to file
-    fn write_state(&self, state: &SharedState) {
-        if let Ok(json) = serde_json::to_string(state) {
-            let _ = fs::write(&self.state_file, json);
+    /// Apply `change` to `state` and write it, unless that would clobber another process.
+    ///
+    /// When another process owns the current drag, its state wins: `state` becomes the on-disk
+    /// state and `change` is dropped. When another process merely wrote since `state` was read,
+    /// `change` is re-applied on top of that newer state and the write retried, so a toggle or a
+    /// click racing another window isn't lost. Returns whether `change` was written.
+    fn write_state(&self, state: &mut SharedState, change: impl Fn(&mut SharedState)) -> bool {
+        change(state);
+        loop {
+            let on_disk: SharedState = fs::read_to_string(&self.state_file)
+                .ok()
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default();
+
+            match check_state_write(&on_disk, state, self.monitor.screen_index) {
+                StateWriteCheck::Accept => {}
+                StateWriteCheck::OwnedByOther => {
+                    tracing::debug!(
+                        "Rejected state write (drag owned by {:?}, version {} vs {} on disk)",
+                        on_disk.drag_owner, state.version, on_disk.version
+                    );
+                    *state = on_disk.clone();
+                    *self.state_cache.borrow_mut() = Some(on_disk);
+                    return false;
+                }
+                StateWriteCheck::Stale => {
+                    tracing::debug!("Stale state write (version {} vs {} on disk) - re-applying", state.version, on_disk.version);
+                    *state = on_disk;
+                    change(state);
+                    continue;
+                }
+            }
+
+            let read_version = on_disk.version;
+            state.version = read_version.max(state.version) + 1;
+            match write_state_file(&self.state_file, state) {
+                Ok(true) => break,
+                // Another process wrote between our read and write - treat ours as stale and re-check
+                Ok(false) => state.version = read_version,
+                Err(e) => {
+                    tracing::warn!("Failed to write state: {}", e);
+                    break;
+                }
+            }
         }
+        *self.state_cache.borrow_mut() = Some(state.clone());
+        true
     }
 
     /// Convert local window coordinates to virtual desktop coordinates
@@ -275,75 +1254,667 @@ impl OverlayApp {
         )
     }
 
-    fn handle_input(&mut self, ctx: &egui::Context) {
-        // Get cursor position relative to this window
-        if let Some(cursor_pos) = ctx.pointer_latest_pos() {
-            self.local_cursor_pos = Some(self.window_to_virtual(cursor_pos));
-        } else {
-            self.local_cursor_pos = None;
+    fn handle_input(&mut self, ctx: &egui::Context) {
+        // Get cursor position relative to this window
+        if let Some(cursor_pos) = ctx.pointer_latest_pos() {
+            self.local_cursor_pos = Some(self.window_to_virtual(cursor_pos));
+        } else {
+            self.local_cursor_pos = None;
+        }
+
+        // Read current state
+        let mut state = self.read_state();
+
+        // Live mode: nothing to select yet, F grabs the frame the user is looking at
+        if !state.frozen {
+            if ctx.input(|i| i.key_pressed(egui::Key::F)) {
+                self.freeze_frame(ctx, &mut state);
+            }
+            return;
+        }
+
+        // Scroll capture: input belongs to the app underneath, the capture thread watches Enter/Escape
+        if state.scrolling {
+            if ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
+                if let Some(job) = &self.scroll_job {
+                    job.finish();
+                }
+            }
+            self.poll_scroll_capture(&mut state);
+            return;
+        }
+
+        // Handle C key (toggle color picker; Ctrl+C arrives as Event::Copy, not as C)
+        if ctx.input(|i| i.key_pressed(egui::Key::C) && !i.modifiers.ctrl) {
+            self.write_state(&mut state, |s| s.picker_mode = !s.picker_mode);
+            tracing::info!("Color picker {}", if state.picker_mode { "enabled" } else { "disabled" });
+        }
+
+        // Color picker: click copies the hex color and closes all windows
+        if state.picker_mode {
+            if ctx.input(|i| i.pointer.primary_pressed()) {
+                if let Some(pos) = self.local_cursor_pos {
+                    match self.copy_color_at(pos) {
+                        Ok(hex) => {
+                            tracing::info!("🎨 Copied color {} to clipboard", hex);
+                            self.write_state(&mut state, |s| {
+                                s.action = Some(OverlayAction::Color);
+                                s.should_close = true;
+                            });
+                        }
+                        Err(e) => tracing::error!("Failed to copy color: {}", e),
+                    }
+                }
+            }
+            return;
+        }
+
+        // Annotation toolbar + drawing (once a selection exists and it is not being dragged)
+        if !state.is_dragging {
+            if let Some(selection) = state.to_rect() {
+                if ctx.input(|i| i.pointer.primary_pressed()) {
+                    if let Some(pos) = self.local_cursor_pos {
+                        let clicked = self.toolbar_buttons(selection)
+                            .into_iter()
+                            .find(|(_, _, button)| button.contains(pos));
+                        if let Some((tool, ..)) = clicked {
+                            self.write_state(&mut state, |s| s.tool = tool);
+                            tracing::info!("Annotation tool: {:?}", tool);
+                            return;
+                        }
+                        if let Some(tool) = state.tool {
+                            self.write_state(&mut state, |s| {
+                                s.set_drag_start(Some(pos));
+                                s.draft = Some(Annotation::new(tool, pos, pos));
+                            });
+                            return;
+                        }
+                        // Select tool: pressing on an annotation moves it instead of starting a new selection
+                        if state.annotation_at(pos).is_some() {
+                            self.write_state(&mut state, |s| {
+                                s.grab = s.annotation_at(pos).map(|index| AnnotationGrab {
+                                    index,
+                                    start: [pos.x, pos.y],
+                                    original: s.annotations[index].clone(),
+                                });
+                            });
+                            return;
+                        }
+                    }
+                }
+
+                if state.grab.is_some() {
+                    let cursor = self.local_cursor_pos;
+                    let released = ctx.input(|i| i.pointer.primary_released());
+                    self.write_state(&mut state, |s| {
+                        let Some(grab) = s.grab.clone() else {
+                            return;
+                        };
+                        if let Some(current) = cursor {
+                            let delta = current - egui::pos2(grab.start[0], grab.start[1]);
+                            if let Some(annotation) = s.annotations.get_mut(grab.index) {
+                                *annotation = grab.original.translated(delta);
+                            }
+                        }
+                        if released {
+                            s.grab = None;
+                            if s.annotations.get(grab.index).is_some_and(|moved| *moved != grab.original) {
+                                let mut before = s.annotations.clone();
+                                before[grab.index] = grab.original;
+                                s.push_history(before);
+                            }
+                        }
+                    });
+                    return;
+                }
+
+                if let (Some(tool), Some(_)) = (state.tool, &state.draft) {
+                    let cursor = self.local_cursor_pos;
+                    let released = ctx.input(|i| i.pointer.primary_released());
+                    self.write_state(&mut state, |s| {
+                        if let (Some(start), Some(current), Some(_)) = (s.drag_start_pos(), cursor, &s.draft) {
+                            s.draft = Some(Annotation::new(tool, start, current));
+                        }
+                        if released {
+                            if let Some(annotation) = s.draft.take().filter(Annotation::is_meaningful) {
+                                s.push_history(s.annotations.clone());
+                                s.annotations.push(annotation);
+                            }
+                            s.set_drag_start(None);
+                        }
+                    });
+                    return;
+                }
+            }
+        }
+
+        // Handle Ctrl+Z (undo) and Ctrl+Shift+Z / Ctrl+Y (redo) of annotation edits
+        let (undo, redo) = ctx.input(|i| {
+            let z = i.modifiers.command && i.key_pressed(egui::Key::Z);
+            let y = i.modifiers.command && i.key_pressed(egui::Key::Y);
+            (z && !i.modifiers.shift, (z && i.modifiers.shift) || y)
+        });
+        if (undo && !state.undo_stack.is_empty()) || (redo && !state.redo_stack.is_empty()) {
+            self.write_state(&mut state, |s| {
+                if undo {
+                    s.undo();
+                } else {
+                    s.redo();
+                }
+            });
+        }
+
+        // Handle Delete/Backspace (remove the annotation under the cursor)
+        if ctx.input(|i| i.key_pressed(egui::Key::Delete) || i.key_pressed(egui::Key::Backspace)) {
+            if let Some(pos) = self.local_cursor_pos.filter(|pos| state.annotation_at(*pos).is_some()) {
+                self.write_state(&mut state, |s| {
+                    if let Some(index) = s.annotation_at(pos) {
+                        s.push_history(s.annotations.clone());
+                        s.annotations.remove(index);
+                    }
+                });
+            }
+        }
+
+        // Handle mouse button press (start drag)
+        if ctx.input(|i| i.pointer.primary_pressed()) {
+            if let Some(pos) = self.local_cursor_pos {
+                let screen_index = self.monitor.screen_index;
+                self.write_state(&mut state, |s| {
+                    s.is_dragging = true;
+                    s.drag_owner = Some(screen_index);
+                    s.set_drag_start(Some(pos));
+                    s.set_rect(Some(egui::Rect::from_min_max(pos, pos)));
+                });
+                tracing::info!("Started drag at virtual pos: {:?}", pos);
+            }
+        }
+
+        // Handle mouse drag (update selection) - only in the process that owns the drag
+        if state.is_dragging && state.drag_owner == Some(self.monitor.screen_index) {
+            if let (Some(start), Some(mut current)) = (state.drag_start_pos(), self.local_cursor_pos) {
+                // Shift held + ratio selected → constrain around the drag anchor
+                if let Some(ratio) = state.aspect_lock {
+                    if ctx.input(|i| i.modifiers.shift) {
+                        current = constrain_to_aspect(start, current, ratio);
+                    }
+                }
+
+                // Optional maximum: the drag stops growing once a side reaches the cap
+                if let Some([max_w, max_h]) = self.config.max_selection_size.map(|max| max.map(|side| side as f32)) {
+                    current.x = start.x + (current.x - start.x).clamp(-max_w, max_w);
+                    current.y = start.y + (current.y - start.y).clamp(-max_h, max_h);
+                }
+
+                // Kept even below the minimum so it can be drawn as "too small"; release discards it
+                self.write_state(&mut state, |s| s.set_rect(Some(egui::Rect::from_two_pos(start, current))));
+            }
+        }
+
+        // Handle mouse button release (end drag)
+        if ctx.input(|i| i.pointer.primary_released()) {
+            if state.is_dragging && state.drag_owner == Some(self.monitor.screen_index) {
+                if state.to_rect().is_some_and(|rect| self.selection_too_small(rect)) {
+                    tracing::info!("Selection below {}px minimum - discarded", self.config.min_selection_size);
+                }
+                self.write_state(&mut state, |s| {
+                    s.is_dragging = false;
+                    s.drag_owner = None;
+                    if s.to_rect().is_some_and(|rect| self.selection_too_small(rect)) {
+                        s.set_rect(None);
+                    }
+                });
+                if let Some(rect) = state.to_rect() {
+                    tracing::info!(
+                        "Selection complete: ({:.0},{:.0}) → ({:.0},{:.0}) [{}×{}]",
+                        rect.min.x, rect.min.y,
+                        rect.max.x, rect.max.y,
+                        rect.width(), rect.height()
+                    );
+                    match self.on_release {
+                        SelectionAction::SaveFile => self.finish_with_save(&mut state, rect),
+                        SelectionAction::CopyClipboard => self.finish_with_copy(&mut state, rect),
+                        SelectionAction::StayOpen => {}
+                    }
+                }
+            }
+        }
+
+        // Handle D key (toggle dimming to judge edges against the undimmed screenshot)
+        if ctx.input(|i| i.key_pressed(egui::Key::D)) {
+            self.write_state(&mut state, |s| s.dim_enabled = !s.dim_enabled);
+            tracing::info!("Dimming {}", if state.dim_enabled { "enabled" } else { "disabled" });
+        }
+
+        // Handle B key (switch between fixed and auto-contrast border)
+        if ctx.input(|i| i.key_pressed(egui::Key::B)) {
+            self.write_state(&mut state, |s| {
+                s.border_mode = match s.border_mode {
+                    BorderMode::Fixed => BorderMode::AutoContrast,
+                    BorderMode::AutoContrast => BorderMode::Fixed,
+                };
+            });
+            tracing::info!("Border mode: {:?}", state.border_mode);
+        }
+
+        // Arrow keys: move selection 1px (Shift: 10px), Ctrl+arrows: resize bottom-right corner.
+        // Only the focused window's process handles them (keys only reach that one anyway), so two processes
+        // never fight over state.json and --only-monitor N works for any N
+        if ctx.input(|i| i.focused) && !state.is_dragging {
+            if let Some(rect) = state.to_rect() {
+                let (delta, step_mods) = ctx.input(|i| {
+                    let mut delta = egui::Vec2::ZERO;
+                    if i.key_pressed(egui::Key::ArrowLeft) { delta.x -= 1.0; }
+                    if i.key_pressed(egui::Key::ArrowRight) { delta.x += 1.0; }
+                    if i.key_pressed(egui::Key::ArrowUp) { delta.y -= 1.0; }
+                    if i.key_pressed(egui::Key::ArrowDown) { delta.y += 1.0; }
+                    (delta, i.modifiers)
+                });
+
+                if delta != egui::Vec2::ZERO {
+                    let delta = if step_mods.shift { delta * 10.0 } else { delta };
+                    let bounds = self.virtual_desktop_bounds;
+                    let adjusted = if step_mods.ctrl {
+                        // resize: keep min corner, clamp max corner to bounds and minimum size
+                        let max = (rect.max + delta)
+                            .max(rect.min + egui::Vec2::splat(self.config.min_selection_size as f32))
+                            .min(bounds.max);
+                        egui::Rect::from_min_max(rect.min, max)
+                    } else {
+                        // move: translate, then shift back inside the virtual desktop
+                        let moved = rect.translate(delta);
+                        let offset = (bounds.min - moved.min).max(egui::Vec2::ZERO)
+                            + (bounds.max - moved.max).min(egui::Vec2::ZERO);
+                        moved.translate(offset)
+                    };
+                    self.write_state(&mut state, |s| s.set_rect(Some(adjusted)));
+                }
+            }
+        }
+
+        // Handle A key (cycle aspect lock: 1:1 → 4:3 → 16:9 → free)
+        if ctx.input(|i| i.key_pressed(egui::Key::A)) {
+            self.write_state(&mut state, |s| s.aspect_lock = next_aspect_lock(s.aspect_lock));
+            tracing::info!("Aspect lock: {:?}", state.aspect_lock);
+        }
+
+        // Handle G key (cycle composition guides)
+        if ctx.input(|i| i.key_pressed(egui::Key::G)) {
+            self.write_state(&mut state, |s| s.guide = s.guide.next());
+            tracing::info!("Guide: {:?}", state.guide);
+        }
+
+        // Handle Escape key (cancel and close)
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            tracing::info!("Escape pressed, signaling all windows to close");
+            self.write_state(&mut state, |s| s.should_close = true);
+        }
+
+        // Handle Enter key (save selection to disk and close all windows)
+        if ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
+            if let Some(rect) = state.to_rect() {
+                self.finish_with_save(&mut state, rect);
+            }
+        }
+
+        // Handle T key (OCR the selection, copy the text and close all windows)
+        if ctx.input(|i| i.key_pressed(egui::Key::T)) {
+            if let Some(rect) = state.to_rect() {
+                match self.copy_selection_text(rect) {
+                    Ok(text) => {
+                        tracing::info!("🔤 Copied {} OCR chars to clipboard", text.chars().count());
+                        self.write_state(&mut state, |s| {
+                            s.action = Some(OverlayAction::Text);
+                            s.text = Some(text.clone());
+                            s.should_close = true;
+                        });
+                    }
+                    Err(e) => tracing::error!("OCR failed: {}", e),
+                }
+            }
+        }
+
+        // Handle Ctrl+C (copy selection to clipboard and close all windows)
+        // NOTE: egui-winit turns Ctrl+C into Event::Copy instead of a Key::C press
+        let copy_requested = ctx.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy)));
+        if copy_requested {
+            if let Some(rect) = state.to_rect() {
+                self.finish_with_copy(&mut state, rect);
+            }
+        }
+
+        // Handle P key (pin the selection as a floating always-on-top window and close all windows)
+        if ctx.input(|i| i.key_pressed(egui::Key::P)) {
+            if let Some(rect) = state.to_rect() {
+                match self.pin_selection(rect) {
+                    Ok(path) => {
+                        tracing::info!("📌 Pinned selection from {}", path.display());
+                        self.write_state(&mut state, |s| {
+                            s.action = Some(OverlayAction::Pinned);
+                            s.should_close = true;
+                        });
+                    }
+                    Err(e) => tracing::error!("Failed to pin selection: {}", e),
+                }
+            }
+        }
+
+        // Handle S key (scroll capture: scroll the content under the selection, Enter stitches and saves)
+        if ctx.input(|i| i.key_pressed(egui::Key::S)) && !state.is_dragging {
+            if let Some(rect) = state.to_rect() {
+                let capture = move || capture_live_region(rect);
+                self.scroll_job = Some(scroll_capture::Job::start(capture, ctx.clone()));
+                self.write_state(&mut state, |s| s.scrolling = true);
+                tracing::info!("📜 Scroll capture started - scroll, then Enter to finish or ESC to cancel");
+            }
+        }
+    }
+
+    /// Scroll capture thread done: save the stitched image (Enter) or close (Escape)
+    fn poll_scroll_capture(&mut self, state: &mut SharedState) {
+        let job = match self.scroll_job.take() {
+            Some(job) if job.is_done() => job,
+            other => {
+                self.scroll_job = other;
+                return;
+            }
+        };
+
+        let mut saved = None;
+        if let Some(stitcher) = job.join() {
+            if !stitcher.moved() {
+                tracing::info!("📜 Content never moved - nothing to stitch, saving a single frame");
+            }
+            let frames = stitcher.frames();
+            let image = stitcher.finish();
+            match self.config.save_output(&image, self.monitor.screen_index) {
+                Ok((path, pages)) => {
+                    tracing::info!(
+                        "💾 Saved scroll capture ({} frame(s), {}×{}) to {}",
+                        frames, image.width(), image.height(), path.display()
+                    );
+                    saved = Some((path, pages));
+                    if self.config.capture_sound {
+                        self.play_capture_sound();
+                    }
+                }
+                Err(e) => tracing::error!("Failed to save scroll capture: {}", e),
+            }
+        }
+
+        self.write_state(state, |s| {
+            if let Some((path, pages)) = &saved {
+                s.action = Some(OverlayAction::Saved);
+                s.saved_path = Some(path.clone());
+                s.saved_pages = pages.clone();
+            }
+            s.scrolling = false;
+            s.should_close = true;
+        });
+    }
+
+    /// Shutter sound on a background thread (debounced); main joins it before the process exits
+    fn play_capture_sound(&self) {
+        let handle = capture_sound::play(self.config.capture_sound_volume, self.config.capture_sound_file.clone());
+        if let Some(handle) = handle {
+            *PENDING_SOUND.lock().unwrap() = Some(handle);
+        }
+    }
+
+    /// Save the selection to disk and signal every window to close (Enter, or release with save_file)
+    fn finish_with_save(&self, state: &mut SharedState, rect: egui::Rect) {
+        match self.save_selection(rect) {
+            Ok((path, pages)) => {
+                tracing::info!("💾 Saved selection to {}", path.display());
+                self.write_state(state, |s| {
+                    s.action = Some(OverlayAction::Saved);
+                    s.saved_path = Some(path.clone());
+                    s.saved_pages = pages.clone();
+                    s.should_close = true;
+                });
+                if self.config.capture_sound {
+                    self.play_capture_sound();
+                }
+            }
+            Err(e) => tracing::error!("Failed to save selection: {}", e),
+        }
+    }
+
+    /// Copy the selection to the clipboard and signal every window to close (Ctrl+C, or release with copy_clipboard)
+    fn finish_with_copy(&self, state: &mut SharedState, rect: egui::Rect) {
+        match self.copy_selection(rect) {
+            Ok(size) => {
+                tracing::info!("📋 Copied selection ({}×{}) to clipboard", size[0], size[1]);
+                self.write_state(state, |s| {
+                    s.action = Some(OverlayAction::Copied);
+                    s.copied_size = Some(size);
+                    s.should_close = true;
+                });
+            }
+            Err(e) => tracing::error!("Failed to copy selection: {}", e),
+        }
+    }
+
+    /// Copy the color under a virtual-desktop point as #RRGGBB.
+    /// Tiles are full resolution, so sample_pixel maps window → image pixels exactly as painted.
+    fn copy_color_at(&self, virtual_pos: egui::Pos2) -> Result<String, Box<dyn std::error::Error>> {
+        let color = self.sample_pixel(self.virtual_to_window(virtual_pos))
+            .ok_or("Cursor is outside the captured image")?;
+        let hex = color_hex(color);
+        arboard::Clipboard::new()?.set_text(hex.clone())?;
+        Ok(hex)
+    }
+
+    /// OCR the selection (same compose as save) and copy the text; returns the char count
+    fn copy_selection_text(&self, rect: egui::Rect) -> Result<String, Box<dyn std::error::Error>> {
+        let img = self.compose_selection(rect)?;
+        let text = ocr_engine::recognize(img.as_raw(), img.width(), img.height(), self.config.ocr_language.as_deref())?;
+        if text.trim().is_empty() {
+            return Err("No text recognized".into());
+        }
+        arboard::Clipboard::new()?.set_text(text.clone())?;
+        Ok(text)
+    }
+
+    /// Copy the selection to the clipboard as a bitmap (works across monitors, same compose as save)
+    fn copy_selection(&self, rect: egui::Rect) -> Result<[u32; 2], Box<dyn std::error::Error>> {
+        let img = self.compose_output(rect)?;
+        let size = [img.width(), img.height()];
+        let mut clipboard = arboard::Clipboard::new()?;
+        clipboard.set_image(arboard::ImageData {
+            width: img.width() as usize,
+            height: img.height() as usize,
+            bytes: std::borrow::Cow::Owned(img.into_raw()),
+        })?;
+        Ok(size)
+    }
+
+    /// Compose the selection (virtual desktop coords) from the frozen per-monitor PNGs.
+    ///
+    /// Crop and stitch come from region.rs, shared with `capture_region_and_save` in
+    /// screenshot_new.rs, so both render at the highest DPI among the monitors touched; this
+    /// one just reads the screenshots taken before the overlay appeared instead of recapturing.
+    fn compose_selection(&self, rect: egui::Rect) -> Result<image::RgbaImage, Box<dyn std::error::Error>> {
+        let sel_x = rect.min.x.round() as i32;
+        let sel_y = rect.min.y.round() as i32;
+        let selection = region::Bounds::new(
+            sel_x,
+            sel_y,
+            (rect.max.x.round() as i32 - sel_x).max(0) as u32,
+            (rect.max.y.round() as i32 - sel_y).max(0) as u32,
+        );
+        let bounds: Vec<region::Bounds> = self.all_monitors.iter()
+            .map(|m| region::Bounds::new(m.x, m.y, m.width, m.height))
+            .collect();
+
+        let mut pieces = Vec::new();
+        for overlap in region::overlaps(selection, &bounds) {
+            let src = image::open(&self.all_monitors[overlap.monitor].image_path)?.to_rgba8();
+            let piece = region::crop_overlap(&src, bounds[overlap.monitor], &overlap);
+            pieces.push((overlap, piece));
+        }
+
+        Ok(region::stitch(selection, pieces)?)
+    }
+
+    /// Final image for save/copy: composed selection with annotations and redactions baked in
+    fn compose_output(&self, rect: egui::Rect) -> Result<image::RgbaImage, Box<dyn std::error::Error>> {
+        let mut img = self.compose_selection(rect)?;
+        let state = self.read_state();
+        if !state.annotations.is_empty() {
+            let scale = img.width() as f32 / rect.width().round().max(1.0);
+            bake_annotations(&mut img, &state.annotations, rect.min, scale);
         }
+        Ok(img)
+    }
 
-        // Read current state
-        let mut state = self.read_state();
+    /// Annotation toolbar buttons (tool, label, rect in virtual coords) under the selection,
+    /// or inside its bottom edge when there is no room below
+    fn toolbar_buttons(&self, selection: egui::Rect) -> [(Option<AnnotationTool>, &'static str, egui::Rect); 6] {
+        const BUTTON: egui::Vec2 = egui::vec2(80.0, 26.0);
+        const GAP: f32 = 4.0;
 
-        // Handle mouse button press (start drag)
-        if ctx.input(|i| i.pointer.primary_pressed()) {
-            if let Some(pos) = self.local_cursor_pos {
-                state.is_dragging = true;
-                state.set_drag_start(Some(pos));
-                state.set_rect(Some(egui::Rect::from_min_max(pos, pos)));
-                self.write_state(&state);
-                tracing::info!("Started drag at virtual pos: {:?}", pos);
-            }
+        let mut top = selection.max.y + 8.0;
+        if top + BUTTON.y > self.virtual_desktop_bounds.max.y {
+            top = selection.max.y - BUTTON.y - 8.0;
         }
+        let button = |i: usize| egui::Rect::from_min_size(
+            egui::pos2(selection.min.x + i as f32 * (BUTTON.x + GAP), top),
+            BUTTON,
+        );
 
-        // Handle mouse drag (update selection)
-        if state.is_dragging {
-            if let (Some(start), Some(current)) = (state.drag_start_pos(), self.local_cursor_pos) {
-                let min_x = start.x.min(current.x);
-                let min_y = start.y.min(current.y);
-                let max_x = start.x.max(current.x);
-                let max_y = start.y.max(current.y);
+        [
+            (None, "Select", button(0)),
+            (Some(AnnotationTool::Arrow), "Arrow", button(1)),
+            (Some(AnnotationTool::Rect), "Rect", button(2)),
+            (Some(AnnotationTool::Highlight), "Highlight", button(3)),
+            (Some(AnnotationTool::Blur), "Blur", button(4)),
+            (Some(AnnotationTool::Pixelate), "Pixelate", button(5)),
+        ]
+    }
 
-                let width = max_x - min_x;
-                let height = max_y - min_y;
+    fn render_toolbar(&self, painter: &egui::Painter, selection: egui::Rect, active: Option<AnnotationTool>) {
+        for (tool, label, button) in self.toolbar_buttons(selection) {
+            let rect = egui::Rect::from_min_max(self.virtual_to_window(button.min), self.virtual_to_window(button.max));
+            let fill = if tool == active {
+                egui::Color32::from_rgb(59, 130, 246)
+            } else {
+                egui::Color32::from_black_alpha(200)
+            };
+            painter.rect_filled(rect, 3.0, fill);
+            painter.text(rect.center(), egui::Align2::CENTER_CENTER, label, egui::FontId::proportional(13.0), egui::Color32::WHITE);
+        }
+    }
 
-                if width >= MIN_SELECTION_SIZE && height >= MIN_SELECTION_SIZE {
-                    state.set_rect(Some(egui::Rect::from_min_max(
-                        egui::pos2(min_x, min_y),
-                        egui::pos2(max_x, max_y)
-                    )));
-                } else {
-                    state.set_rect(None);
+    fn render_annotation(&self, painter: &egui::Painter, annotation: &Annotation) {
+        let stroke = egui::Stroke::new(ANNOTATION_WIDTH, ANNOTATION_COLOR);
+        let to_window = |p: [f32; 2]| self.virtual_to_window(egui::pos2(p[0], p[1]));
+        match annotation {
+            Annotation::Arrow { from, to } => {
+                let (from, to) = (to_window(*from), to_window(*to));
+                painter.line_segment([from, to], stroke);
+                for barb in arrow_head(from, to, ARROW_HEAD_LEN) {
+                    painter.line_segment([to, barb], stroke);
                 }
-                self.write_state(&state);
+            }
+            Annotation::Rect { rect } => {
+                let rect = egui::Rect::from_min_max(to_window([rect[0], rect[1]]), to_window([rect[2], rect[3]]));
+                painter.rect_stroke(rect, 0.0, stroke);
+            }
+            Annotation::Highlight { rect } => {
+                let rect = egui::Rect::from_min_max(to_window([rect[0], rect[1]]), to_window([rect[2], rect[3]]));
+                let [r, g, b, a] = HIGHLIGHT_RGBA;
+                painter.rect_filled(rect, 0.0, egui::Color32::from_rgba_unmultiplied(r, g, b, a));
+            }
+            // Preview both redactions as a mosaic of the screenshot (blur approximated with finer cells)
+            Annotation::Blur { rect } => {
+                let rect = egui::Rect::from_min_max(to_window([rect[0], rect[1]]), to_window([rect[2], rect[3]]));
+                self.render_mosaic(painter, rect, BLUR_SIGMA / 2.0);
+            }
+            Annotation::Pixelate { rect } => {
+                let rect = egui::Rect::from_min_max(to_window([rect[0], rect[1]]), to_window([rect[2], rect[3]]));
+                self.render_mosaic(painter, rect, PIXELATE_BLOCK);
             }
         }
+    }
 
-        // Handle mouse button release (end drag)
-        if ctx.input(|i| i.pointer.primary_released()) {
-            if state.is_dragging {
-                state.is_dragging = false;
-                self.write_state(&state);
-                if let Some(rect) = state.to_rect() {
-                    tracing::info!(
-                        "Selection complete: ({:.0},{:.0}) → ({:.0},{:.0}) [{}×{}]",
-                        rect.min.x, rect.min.y,
-                        rect.max.x, rect.max.y,
-                        rect.width(), rect.height()
-                    );
+    /// Fill `rect` (window coords) with cells averaging a 4×4 sample grid of the screenshot each
+    fn render_mosaic(&self, painter: &egui::Painter, rect: egui::Rect, cell: f32) {
+        let rect = rect.intersect(egui::Rect::from_min_size(egui::pos2(0.0, 0.0), self.view_size));
+        if rect.is_negative() {
+            return;
+        }
+
+        let mut y = rect.min.y;
+        while y < rect.max.y {
+            let mut x = rect.min.x;
+            while x < rect.max.x {
+                let cell_rect = egui::Rect::from_min_max(
+                    egui::pos2(x, y),
+                    egui::pos2((x + cell).min(rect.max.x), (y + cell).min(rect.max.y)),
+                );
+                let (mut sum, mut count) = ([0u32; 3], 0u32);
+                for sy in 0..4 {
+                    for sx in 0..4 {
+                        let sample = cell_rect.min + cell_rect.size() * egui::vec2((sx as f32 + 0.5) / 4.0, (sy as f32 + 0.5) / 4.0);
+                        if let Some(color) = self.sample_pixel(sample) {
+                            sum[0] += color.r() as u32;
+                            sum[1] += color.g() as u32;
+                            sum[2] += color.b() as u32;
+                            count += 1;
+                        }
+                    }
                 }
+                if count > 0 {
+                    let avg = |c: u32| (c / count) as u8;
+                    painter.rect_filled(cell_rect, 0.0, egui::Color32::from_rgb(avg(sum[0]), avg(sum[1]), avg(sum[2])));
+                }
+                x += cell;
             }
+            y += cell;
         }
+    }
 
-        // Handle Escape key (cancel and close)
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
-            tracing::info!("Escape pressed, signaling all windows to close");
-            state.should_close = true;
-            self.write_state(&state);
+    /// Save the selection to the configured output dir/template/format (same as capture_region_and_save)
+    fn save_selection(&self, rect: egui::Rect) -> Result<(PathBuf, Vec<PathBuf>), Box<dyn std::error::Error>> {
+        let img = self.config.post_process(self.compose_output(rect)?);
+
+        // {monitor} = monitor containing the selection's top-left corner
+        let monitor = self.all_monitors.iter()
+            .find(|m| {
+                let bounds = egui::Rect::from_min_size(
+                    egui::pos2(m.x as f32, m.y as f32),
+                    egui::vec2(m.width as f32, m.height as f32),
+                );
+                bounds.contains(rect.min)
+            })
+            .map(|m| m.screen_index)
+            .unwrap_or(self.monitor.screen_index);
+
+        self.config.save_output(&img, monitor)
+    }
+
+    /// Save the composed selection to a temp PNG and spawn a detached `--pin` window over the same spot.
+    /// Pins live outside egui_overlay so the parent's temp cleanup can't delete them first.
+    fn pin_selection(&self, rect: egui::Rect) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let img = self.compose_output(rect)?;
+
+        let pin_dir = std::env::temp_dir().join("aplikacja3").join("pins");
+        fs::create_dir_all(&pin_dir)?;
+        let path = pin_dir.join(format!("pin_{}.png", chrono::Local::now().format("%Y%m%d_%H%M%S_%3f")));
+        img.save(&path)?;
+
+        let mut command = Command::new(std::env::current_exe()?);
+        command
+            .arg("--pin")
+            .arg(&path)
+            .args([rect.min.x, rect.min.y, rect.width(), rect.height()].map(|v| v.round().to_string()));
+        if let Some(language) = &self.config.ocr_language {
+            command.arg("--ocr-language").arg(language);
         }
+        command.spawn()?;
 
-        // TODO Phase 2: Handle Enter/Ctrl+C to save selection
+        Ok(path)
     }
 
     fn render_overlay(&self, ui: &mut egui::Ui) {
@@ -353,70 +1924,77 @@ impl OverlayApp {
         // Ensures window receives mouse events and prevents click-through bug
         let full_rect = egui::Rect::from_min_size(
             egui::pos2(0.0, 0.0),
-            egui::vec2(self.texture_width as f32, self.texture_height as f32),
-        );
-        painter.rect_filled(
-            full_rect,
-            0.0,
-            egui::Color32::from_rgba_premultiplied(0, 0, 0, 3), // ~1% opacity
+            self.view_size,
         );
-
-        // LAYER 1: Render monitor screenshot at (0,0) in window coordinates
-        if let Some(texture) = &self.texture {
-            let rect = full_rect; // Reuse full_rect from LAYER 0
-            painter.image(
-                texture.id(),
-                rect,
-                egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
-                egui::Color32::WHITE,
+        if !self.passthrough {
+            painter.rect_filled(
+                full_rect,
+                0.0,
+                egui::Color32::from_rgba_premultiplied(0, 0, 0, 3), // ~1% opacity
             );
         }
 
-        // DEBUG OVERLAY - Large text showing monitor identity and loaded image
-        let texture_size = if let Some(tex) = &self.texture {
-            format!("{}×{}", tex.size()[0], tex.size()[1])
-        } else {
-            "NO TEXTURE".to_string()
-        };
+        let state = self.read_state();
 
-        let image_filename = self.monitor.image_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("UNKNOWN");
+        // SCROLL CAPTURE: the real screen shows through; outline drawn just outside the selection
+        // so it never ends up in the captured frames
+        if state.scrolling {
+            if let Some(rect) = state.to_rect() {
+                let outline = egui::Rect::from_min_max(
+                    self.virtual_to_window(rect.min),
+                    self.virtual_to_window(rect.max),
+                ).expand(3.0);
+                painter.rect_stroke(outline, 0.0, egui::Stroke::new(2.0, egui::Color32::from_rgb(59, 130, 246)));
+                painter.text(
+                    outline.left_top() - egui::vec2(0.0, 6.0),
+                    egui::Align2::LEFT_BOTTOM,
+                    "Scroll capture • scroll the content • Enter to finish • ESC to cancel",
+                    egui::FontId::proportional(16.0),
+                    egui::Color32::WHITE,
+                );
+            }
+            return;
+        }
 
-        let debug_text = format!(
-            "🔍 DEBUG MONITOR {}\nPos: ({}, {})\nSize: {}×{}\nImage: {}\nTexture: {}",
-            self.monitor.screen_index,
-            self.monitor.x,
-            self.monitor.y,
-            self.monitor.width,
-            self.monitor.height,
-            image_filename,
-            texture_size
-        );
+        // LIVE MODE: keep the real screen visible until F freezes a frame
+        if !state.frozen {
+            if self.monitor.screen_index == 0 {
+                painter.text(
+                    egui::pos2(self.view_size.x / 2.0, 20.0),
+                    egui::Align2::CENTER_TOP,
+                    "Live view • F to freeze the current frame • ESC to cancel",
+                    egui::FontId::proportional(18.0),
+                    egui::Color32::WHITE,
+                );
+            }
+            return;
+        }
 
-        // Background for debug text
-        let debug_bg_rect = egui::Rect::from_min_size(
-            egui::pos2(10.0, 10.0),
-            egui::vec2(400.0, 160.0),
-        );
-        painter.rect_filled(
-            debug_bg_rect,
-            4.0,
-            egui::Color32::from_rgba_premultiplied(0, 0, 0, 200),
-        );
+        // LAYER 1: Render monitor screenshot tiles adjacently, scaled from image pixels to the window
+        if let Some(image) = &self.pixels {
+            let scale_x = full_rect.width() / image.size[0] as f32;
+            let scale_y = full_rect.height() / image.size[1] as f32;
 
-        // Debug text in bright red
-        painter.text(
-            egui::pos2(20.0, 20.0),
-            egui::Align2::LEFT_TOP,
-            debug_text,
-            egui::FontId::proportional(24.0),
-            egui::Color32::from_rgb(255, 50, 50),
-        );
+            for tile in &self.tiles {
+                let rect = egui::Rect::from_min_size(
+                    egui::pos2(tile.x as f32 * scale_x, tile.y as f32 * scale_y),
+                    egui::vec2(tile.width as f32 * scale_x, tile.height as f32 * scale_y),
+                );
+                painter.image(
+                    tile.texture.id(),
+                    rect,
+                    egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+                    egui::Color32::WHITE,
+                );
+            }
+        }
+
+        // DEBUG OVERLAY - only with AP30D_OVERLAY_DEBUG set (skipped entirely otherwise)
+        if self.debug {
+            self.render_debug_info(painter);
+        }
 
         // LAYER 2: Dark overlay with selection cutout
-        let state = self.read_state();
 
         // Get selection rect in virtual coordinates, convert to window coordinates
         let selection_rect_window = state.to_rect().map(|rect| {
@@ -426,36 +2004,63 @@ impl OverlayApp {
             )
         });
 
-        self.render_dark_overlay_with_cutout(
-            painter,
-            selection_rect_window,
-            egui::Color32::from_rgba_premultiplied(0, 0, 0, 128),
-        );
+        if state.dim_enabled {
+            self.render_dark_overlay_with_cutout(
+                painter,
+                selection_rect_window,
+                egui::Color32::from_rgba_premultiplied(0, 0, 0, 128),
+            );
+        }
+
+        // LAYER 2.5: Composition guides over the whole monitor
+        if state.guide != GuideKind::Off {
+            self.render_guides(painter, state.guide);
+        }
+
+        // LAYER 2.6: Crosshair alignment lines
+        self.render_crosshair(painter, &state, selection_rect_window);
 
         // LAYER 3: Selection border and info
         if let Some(selection_window) = selection_rect_window {
             let window_rect = egui::Rect::from_min_size(
                 egui::pos2(0.0, 0.0),
-                egui::vec2(self.texture_width as f32, self.texture_height as f32),
+                self.view_size,
             );
 
+            let too_small = state.is_dragging
+                && state.to_rect().is_some_and(|rect| self.selection_too_small(rect));
+
             let intersection = window_rect.intersect(selection_window);
             if !intersection.is_negative() {
-                // Draw selection border
-                painter.rect_stroke(
-                    intersection,
-                    0.0,
-                    egui::Stroke::new(2.0, egui::Color32::from_rgb(59, 130, 246)),
-                );
+                // Draw selection border (warning color while the drag is below the minimum)
+                match state.border_mode {
+                    _ if too_small => {
+                        let [r, g, b] = TOO_SMALL_RGB;
+                        painter.rect_stroke(intersection, 0.0, egui::Stroke::new(2.0, egui::Color32::from_rgb(r, g, b)));
+                    }
+                    BorderMode::Fixed => {
+                        painter.rect_stroke(
+                            intersection,
+                            0.0,
+                            egui::Stroke::new(2.0, egui::Color32::from_rgb(59, 130, 246)),
+                        );
+                    }
+                    BorderMode::AutoContrast => {
+                        self.render_auto_contrast_border(painter, intersection);
+                    }
+                }
 
                 // Draw selection info (only on primary monitor)
                 if self.monitor.screen_index == 0 {
                     if let Some(full_rect) = state.to_rect() {
-                        let size_text = format!(
+                        let mut size_text = format!(
                             "{} × {}",
                             full_rect.width() as i32,
                             full_rect.height() as i32
                         );
+                        if let Some((ratio_w, ratio_h)) = state.aspect_lock {
+                            size_text.push_str(&format!(" ({}:{})", ratio_w, ratio_h));
+                        }
 
                         let label_pos_virtual = egui::pos2(
                             full_rect.min.x,
@@ -479,29 +2084,358 @@ impl OverlayApp {
                             egui::Color32::from_rgb(59, 130, 246)
                         );
 
-                        // Label text
-                        painter.text(
-                            egui::pos2(label_pos.x + 4.0, label_pos.y + 2.0),
-                            egui::Align2::LEFT_TOP,
-                            size_text,
-                            egui::FontId::proportional(14.0),
-                            egui::Color32::WHITE
-                        );
+                        // Label text
+                        painter.text(
+                            egui::pos2(label_pos.x + 4.0, label_pos.y + 2.0),
+                            egui::Align2::LEFT_TOP,
+                            size_text,
+                            egui::FontId::proportional(14.0),
+                            egui::Color32::WHITE
+                        );
+                    }
+                }
+            }
+        }
+
+        // LAYER 3.2: Annotations (committed + the one being dragged) and their toolbar
+        for annotation in state.annotations.iter().chain(state.draft.iter()) {
+            self.render_annotation(painter, annotation);
+        }
+        if let Some(selection) = state.to_rect() {
+            if !state.is_dragging {
+                self.render_toolbar(painter, selection, state.tool);
+            }
+        }
+
+        // LAYER 3.5: Magnifier loupe + coordinate/color readout next to the cursor
+        // (only on the monitor being hovered, so there's never a duplicate box)
+        if let Some(cursor_virtual) = self.local_cursor_pos {
+            let cursor = self.virtual_to_window(cursor_virtual);
+            if full_rect.contains(cursor) {
+                self.render_loupe(painter, cursor, cursor_virtual);
+                if state.picker_mode {
+                    self.render_picker_swatch(painter, cursor);
+                }
+                if state.is_dragging && state.to_rect().is_some_and(|rect| self.selection_too_small(rect)) {
+                    self.render_too_small_hint(painter, cursor);
+                }
+            }
+        }
+
+        // LAYER 4: Instructions (only on primary monitor when no selection)
+        if self.monitor.screen_index == 0 && state.selection_rect.is_none() {
+            let instructions = format!(
+                "Click and drag to select area (minimum {}px) • Enter to save • Ctrl+C to copy • D to toggle dimming • T to copy text (OCR) • P to pin • S for scroll capture • G for guides • C for color picker • A + Shift-drag for aspect lock • Ctrl+Z / Ctrl+Y to undo/redo annotations • Del removes the annotation under the cursor • ESC to cancel",
+                self.config.min_selection_size
+            );
+            painter.text(
+                egui::pos2(self.view_size.x / 2.0, 20.0),
+                egui::Align2::CENTER_TOP,
+                instructions,
+                egui::FontId::proportional(18.0),
+                egui::Color32::WHITE,
+            );
+        }
+    }
+
+    /// Alignment lines spanning the window: through the cursor before a drag, along all four
+    /// selection edges during one. Selection edges come from the shared state, so they line up
+    /// across monitors; the cursor lines only show on the hovered monitor.
+    fn render_crosshair(&self, painter: &egui::Painter, state: &SharedState, selection_window: Option<egui::Rect>) {
+        let [r, g, b] = CROSSHAIR_RGB;
+        let stroke = egui::Stroke::new(CROSSHAIR_WIDTH, egui::Color32::from_rgba_unmultiplied(r, g, b, CROSSHAIR_ALPHA));
+        let full = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), self.view_size);
+
+        let (xs, ys): (Vec<f32>, Vec<f32>) = if state.is_dragging {
+            let Some(selection) = selection_window else { return };
+            (vec![selection.min.x, selection.max.x], vec![selection.min.y, selection.max.y])
+        } else {
+            let Some(cursor_virtual) = self.local_cursor_pos else { return };
+            let cursor = self.virtual_to_window(cursor_virtual);
+            if !full.contains(cursor) {
+                return;
+            }
+            (vec![cursor.x], vec![cursor.y])
+        };
+
+        for x in xs {
+            if (full.min.x..=full.max.x).contains(&x) {
+                painter.line_segment([egui::pos2(x, full.min.y), egui::pos2(x, full.max.y)], stroke);
+            }
+        }
+        for y in ys {
+            if (full.min.y..=full.max.y).contains(&y) {
+                painter.line_segment([egui::pos2(full.min.x, y), egui::pos2(full.max.x, y)], stroke);
+            }
+        }
+    }
+
+    /// Draw the composition guide in window coordinates, using the configured guide color
+    fn render_guides(&self, painter: &egui::Painter, guide: GuideKind) {
+        let [r, g, b, a] = self.config.guide_color;
+        let stroke = egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(r, g, b, a));
+        let full = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), self.view_size);
+
+        // Largest rectangle of the given ratio centered in the window
+        let centered_box = |ratio_w: f32, ratio_h: f32| {
+            let scale = (full.width() / ratio_w).min(full.height() / ratio_h);
+            egui::Rect::from_center_size(full.center(), egui::vec2(ratio_w * scale, ratio_h * scale))
+        };
+
+        match guide {
+            GuideKind::Off => {}
+            GuideKind::Thirds => {
+                for i in 1..3 {
+                    let x = full.width() * i as f32 / 3.0;
+                    let y = full.height() * i as f32 / 3.0;
+                    painter.line_segment([egui::pos2(x, full.min.y), egui::pos2(x, full.max.y)], stroke);
+                    painter.line_segment([egui::pos2(full.min.x, y), egui::pos2(full.max.x, y)], stroke);
+                }
+            }
+            GuideKind::TitleSafe => {
+                for fraction in [0.9, 0.8] {
+                    let safe = egui::Rect::from_center_size(full.center(), full.size() * fraction);
+                    painter.rect_stroke(safe, 0.0, stroke);
+                }
+            }
+            GuideKind::Aspect16x9 => {
+                painter.rect_stroke(centered_box(16.0, 9.0), 0.0, stroke);
+            }
+            GuideKind::Aspect1x1 => {
+                painter.rect_stroke(centered_box(1.0, 1.0), 0.0, stroke);
+            }
+        }
+    }
+
+    /// Large text showing monitor identity and loaded image (AP30D_OVERLAY_DEBUG only)
+    fn render_debug_info(&self, painter: &egui::Painter) {
+        let texture_size = if let Some(image) = &self.pixels {
+            format!("{}×{} ({} tiles)", image.size[0], image.size[1], self.tiles.len())
+        } else {
+            "NO TEXTURE".to_string()
+        };
+
+        let image_filename = self.monitor.image_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("UNKNOWN");
+
+        let debug_text = format!(
+            "🔍 DEBUG MONITOR {}\nPos: ({}, {})\nSize: {}×{}\nImage: {}\nTexture: {}",
+            self.monitor.screen_index,
+            self.monitor.x,
+            self.monitor.y,
+            self.monitor.width,
+            self.monitor.height,
+            image_filename,
+            texture_size
+        );
+
+        // Background for debug text
+        let debug_bg_rect = egui::Rect::from_min_size(
+            egui::pos2(10.0, 10.0),
+            egui::vec2(400.0, 160.0),
+        );
+        painter.rect_filled(
+            debug_bg_rect,
+            4.0,
+            egui::Color32::from_rgba_premultiplied(0, 0, 0, 200),
+        );
+
+        // Debug text in bright red
+        painter.text(
+            egui::pos2(20.0, 20.0),
+            egui::Align2::LEFT_TOP,
+            debug_text,
+            egui::FontId::proportional(24.0),
+            egui::Color32::from_rgb(255, 50, 50),
+        );
+    }
+
+    /// Zoomed patch of screenshot pixels around the cursor, with a crosshair and a readout of
+    /// the cursor's virtual desktop coordinate and the center pixel's RGB/hex
+    fn render_loupe(&self, painter: &egui::Painter, cursor: egui::Pos2, cursor_virtual: egui::Pos2) {
+        const RADIUS: i64 = 15;  // image pixels on each side of the center
+        const ZOOM: f32 = 8.0;
+        const OFFSET: f32 = 24.0;  // gap between cursor and loupe
+
+        let Some(pixels) = self.pixels.as_ref() else {
+            return;
+        };
+        let [img_w, img_h] = pixels.size;
+        let center_x = (cursor.x / self.view_size.x * img_w as f32).floor() as i64;
+        let center_y = (cursor.y / self.view_size.y * img_h as f32).floor() as i64;
+
+        // Below-right of the cursor, flipped to the other side near the window edges
+        let side = (2 * RADIUS + 1) as f32 * ZOOM;
+        let mut origin = cursor + egui::vec2(OFFSET, OFFSET);
+        if origin.x + side > self.view_size.x {
+            origin.x = cursor.x - OFFSET - side;
+        }
+        if origin.y + side + 24.0 > self.view_size.y {
+            origin.y = cursor.y - OFFSET - side - 24.0;
+        }
+        let loupe_rect = egui::Rect::from_min_size(origin, egui::vec2(side, side));
+        painter.rect_filled(loupe_rect, 0.0, egui::Color32::BLACK);
+
+        for dy in -RADIUS..=RADIUS {
+            for dx in -RADIUS..=RADIUS {
+                let (x, y) = (center_x + dx, center_y + dy);
+                if x < 0 || y < 0 || x >= img_w as i64 || y >= img_h as i64 {
+                    continue;
+                }
+                let color = pixels.pixels[y as usize * img_w + x as usize];
+                let cell = egui::Rect::from_min_size(
+                    origin + egui::vec2((dx + RADIUS) as f32 * ZOOM, (dy + RADIUS) as f32 * ZOOM),
+                    egui::vec2(ZOOM, ZOOM),
+                );
+                painter.rect_filled(cell, 0.0, color);
+            }
+        }
+
+        // Crosshair: outline the center pixel
+        let center_cell = egui::Rect::from_min_size(
+            origin + egui::vec2(RADIUS as f32 * ZOOM, RADIUS as f32 * ZOOM),
+            egui::vec2(ZOOM, ZOOM),
+        );
+        painter.rect_stroke(center_cell, 0.0, egui::Stroke::new(1.0, egui::Color32::RED));
+        painter.rect_stroke(loupe_rect, 0.0, egui::Stroke::new(1.0, egui::Color32::WHITE));
+
+        // Virtual coordinate + color of the center pixel under the loupe
+        if let Some(color) = self.sample_pixel(cursor) {
+            let text = format!(
+                "{}, {}  RGB {} {} {}  {}",
+                cursor_virtual.x.floor() as i32,
+                cursor_virtual.y.floor() as i32,
+                color.r(),
+                color.g(),
+                color.b(),
+                color_hex(color),
+            );
+            let text_pos = egui::pos2(loupe_rect.min.x, loupe_rect.max.y + 2.0);
+            let galley = painter.layout_no_wrap(text.clone(), egui::FontId::monospace(13.0), egui::Color32::WHITE);
+            painter.rect_filled(
+                egui::Rect::from_min_size(text_pos, galley.size() + egui::vec2(8.0, 4.0)),
+                2.0,
+                egui::Color32::from_black_alpha(200),
+            );
+            painter.text(
+                text_pos + egui::vec2(4.0, 2.0),
+                egui::Align2::LEFT_TOP,
+                text,
+                egui::FontId::monospace(13.0),
+                egui::Color32::WHITE,
+            );
+        }
+    }
+
+    /// Selection narrower or shorter than the configured minimum (discarded on release)
+    fn selection_too_small(&self, rect: egui::Rect) -> bool {
+        let min = self.config.min_selection_size as f32;
+        rect.width() < min || rect.height() < min
+    }
+
+    /// "Too small" badge above-right of the cursor while the drag is below the minimum
+    fn render_too_small_hint(&self, painter: &egui::Painter, cursor: egui::Pos2) {
+        const OFFSET: f32 = 24.0;
+
+        let [r, g, b] = TOO_SMALL_RGB;
+        let text = format!("Too small (min {}px)", self.config.min_selection_size);
+        let galley = painter.layout_no_wrap(text.clone(), egui::FontId::proportional(14.0), egui::Color32::WHITE);
+        let size = galley.size() + egui::vec2(8.0, 4.0);
+
+        let mut origin = cursor + egui::vec2(OFFSET, -OFFSET - size.y);
+        if origin.x + size.x > self.view_size.x {
+            origin.x = cursor.x - OFFSET - size.x;
+        }
+        origin.y = origin.y.max(0.0);
+
+        painter.rect_filled(egui::Rect::from_min_size(origin, size), 2.0, egui::Color32::from_rgb(r, g, b));
+        painter.text(
+            origin + egui::vec2(4.0, 2.0),
+            egui::Align2::LEFT_TOP,
+            text,
+            egui::FontId::proportional(14.0),
+            egui::Color32::WHITE,
+        );
+    }
+
+    /// Color swatch + hex string above-left of the cursor (picker mode)
+    fn render_picker_swatch(&self, painter: &egui::Painter, cursor: egui::Pos2) {
+        let Some(color) = self.sample_pixel(cursor) else {
+            return;
+        };
+        let hex = color_hex(color);
+        let galley = painter.layout_no_wrap(hex.clone(), egui::FontId::monospace(14.0), egui::Color32::WHITE);
+        let size = egui::vec2(24.0 + 6.0 + galley.size().x + 8.0, 28.0);
+        let mut origin = cursor - egui::vec2(size.x + 16.0, size.y + 16.0);
+        origin = origin.max(egui::pos2(0.0, 0.0));
+
+        let background = egui::Rect::from_min_size(origin, size);
+        painter.rect_filled(background, 3.0, egui::Color32::from_black_alpha(200));
+        let swatch = egui::Rect::from_min_size(origin + egui::vec2(4.0, 4.0), egui::vec2(20.0, 20.0));
+        painter.rect_filled(swatch, 2.0, color);
+        painter.rect_stroke(swatch, 2.0, egui::Stroke::new(1.0, egui::Color32::WHITE));
+        painter.text(
+            egui::pos2(swatch.max.x + 6.0, background.center().y),
+            egui::Align2::LEFT_CENTER,
+            hex,
+            egui::FontId::monospace(14.0),
+            egui::Color32::WHITE,
+        );
+    }
+
+    /// Sample the screenshot pixel under a window-space position (maps through the downscale factor)
+    fn sample_pixel(&self, window_pos: egui::Pos2) -> Option<egui::Color32> {
+        let pixels = self.pixels.as_ref()?;
+        let [img_w, img_h] = pixels.size;
+        let x = (window_pos.x / self.view_size.x * img_w as f32).floor();
+        let y = (window_pos.y / self.view_size.y * img_h as f32).floor();
+        if x < 0.0 || y < 0.0 || x >= img_w as f32 || y >= img_h as f32 {
+            return None;
+        }
+        Some(pixels.pixels[y as usize * img_w + x as usize])
+    }
+
+    /// Draw the selection border in black or white per segment, based on the average luminance underneath
+    fn render_auto_contrast_border(&self, painter: &egui::Painter, rect: egui::Rect) {
+        const SEGMENT_LEN: f32 = 16.0;
+
+        let edges = [
+            (rect.left_top(), rect.right_top()),
+            (rect.right_top(), rect.right_bottom()),
+            (rect.right_bottom(), rect.left_bottom()),
+            (rect.left_bottom(), rect.left_top()),
+        ];
+
+        for (from, to) in edges {
+            let length = from.distance(to);
+            let segments = (length / SEGMENT_LEN).ceil().max(1.0) as usize;
+
+            for i in 0..segments {
+                let a = from.lerp(to, i as f32 / segments as f32);
+                let b = from.lerp(to, (i + 1) as f32 / segments as f32);
+
+                // Average luminance of a few samples along the segment
+                let samples = 4;
+                let mut luminance_sum = 0.0;
+                let mut count = 0;
+                for s in 0..samples {
+                    let p = a.lerp(b, (s as f32 + 0.5) / samples as f32);
+                    if let Some(c) = self.sample_pixel(p) {
+                        luminance_sum += 0.299 * c.r() as f32 + 0.587 * c.g() as f32 + 0.114 * c.b() as f32;
+                        count += 1;
                     }
                 }
-            }
-        }
+                let luminance = if count > 0 { luminance_sum / count as f32 } else { 0.0 };
 
-        // LAYER 4: Instructions (only on primary monitor when no selection)
-        if self.monitor.screen_index == 0 && state.selection_rect.is_none() {
-            let instructions = "Click and drag to select area (minimum 5px) • ESC to cancel";
-            painter.text(
-                egui::pos2(self.texture_width as f32 / 2.0, 20.0),
-                egui::Align2::CENTER_TOP,
-                instructions,
-                egui::FontId::proportional(18.0),
-                egui::Color32::WHITE,
-            );
+                let color = if luminance > 128.0 {
+                    egui::Color32::BLACK
+                } else {
+                    egui::Color32::WHITE
+                };
+                painter.line_segment([a, b], egui::Stroke::new(2.0, color));
+            }
         }
     }
 
@@ -514,7 +2448,7 @@ impl OverlayApp {
     ) {
         let full_rect = egui::Rect::from_min_size(
             egui::pos2(0.0, 0.0),
-            egui::vec2(self.texture_width as f32, self.texture_height as f32),
+            self.view_size,
         );
 
         if let Some(cutout) = cutout {
@@ -580,6 +2514,9 @@ impl eframe::App for OverlayApp {
     /// CRITICAL: Make background ALMOST transparent (not fully)
     /// Fully transparent windows may trigger WS_EX_TRANSPARENT behavior
     fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
+        if self.passthrough {
+            return [0.0; 4];  // Scroll capture: fully transparent, input goes through anyway
+        }
         [0.0, 0.0, 0.0, 0.01]  // 1% opacity - invisible but captures input
     }
 
@@ -589,20 +2526,30 @@ impl eframe::App for OverlayApp {
             tracing::info!("🚫 ESC pressed - cancelling screenshot, closing all overlays");
             // Signal all monitors to close
             let mut state = self.read_state();
-            state.should_close = true;
-            self.write_state(&state);
+            self.write_state(&mut state, |s| s.should_close = true);
             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
             return;
         }
 
-        // Poll for close signal every 100ms
-        if self.last_state_check.elapsed() > Duration::from_millis(100) {
-            if self.read_state().should_close {
-                tracing::info!("Received close signal, shutting down");
-                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
-                return;
-            }
-            self.last_state_check = Instant::now();
+        // Without a watcher: re-read state.json at most every 100ms
+        if self.state_watcher.is_none() {
+            self.state_dirty.store(true, Ordering::SeqCst);
+            ctx.request_repaint_after(Duration::from_millis(100));
+        }
+
+        // Close signal (and frames frozen by another monitor's process)
+        let state = self.read_state();
+        if state.should_close {
+            tracing::info!("Received close signal, shutting down");
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            return;
+        }
+        if state.frame_version != self.loaded_frame {
+            self.reload_screenshot(ctx, state.frame_version);
+        }
+        if state.scrolling != self.passthrough {
+            self.passthrough = state.scrolling;
+            ctx.send_viewport_cmd(egui::ViewportCommand::MousePassthrough(self.passthrough));
         }
 
         // Handle input
@@ -615,8 +2562,226 @@ impl eframe::App for OverlayApp {
                 self.render_overlay(ui);
             });
 
-        // Request continuous repaint
-        ctx.request_repaint();
+        // No continuous repaint: input events repaint the hovered/dragging monitor,
+        // the state.json watcher repaints the others when another process changes the state
+    }
+}
+
+/// A state.json.lock older than this belongs to a writer that died mid-write and is broken
+const STATE_LOCK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Cross-process mutex around state.json writes: a lock file created with create_new, removed on drop
+struct StateFileLock {
+    path: PathBuf,
+}
+
+impl StateFileLock {
+    fn acquire(state_file: &std::path::Path) -> std::io::Result<Self> {
+        let path = state_file.with_extension("json.lock");
+        let mut waiting_since = Instant::now();
+        loop {
+            match fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if waiting_since.elapsed() > STATE_LOCK_TIMEOUT {
+                        tracing::warn!("Breaking stale state lock {}", path.display());
+                        let _ = fs::remove_file(&path);
+                        waiting_since = Instant::now();
+                    }
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for StateFileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Atomic replace: write state.json.tmp, then rename over state.json (readers never see half a file).
+/// Under StateFileLock, so writers can't interleave; returns false without writing when the file
+/// already holds this version or a newer one (versions on disk only ever go up).
+/// Outcome of checking a local state write against state.json
+#[derive(Debug, PartialEq)]
+enum StateWriteCheck {
+    Accept,
+    /// Another process owns the current drag - its state wins
+    OwnedByOther,
+    /// Another process wrote since `local` was read - re-apply the change on top of theirs
+    Stale,
+}
+
+/// Whether `local` (written by the overlay on `screen_index`) may replace `on_disk`.
+/// Closing always goes through; the drag owner may overwrite newer writes until release.
+fn check_state_write(on_disk: &SharedState, local: &SharedState, screen_index: usize) -> StateWriteCheck {
+    if local.should_close {
+        return StateWriteCheck::Accept;
+    }
+    if on_disk.drag_owner.is_some_and(|owner| owner != screen_index) {
+        return StateWriteCheck::OwnedByOther;
+    }
+    if on_disk.version > local.version && on_disk.drag_owner != Some(screen_index) {
+        return StateWriteCheck::Stale;
+    }
+    StateWriteCheck::Accept
+}
+
+fn write_state_file(path: &std::path::Path, state: &SharedState) -> std::io::Result<bool> {
+    let _lock = StateFileLock::acquire(path)?;
+
+    let on_disk_version = fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str::<SharedState>(&s).ok())
+        .map(|on_disk| on_disk.version);
+    if on_disk_version.is_some_and(|version| version >= state.version) {
+        return Ok(false);
+    }
+
+    let json = serde_json::to_string(state)?;
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, path)?;
+    Ok(true)
+}
+
+/// Watch state.json and repaint when another overlay process changes it.
+/// Watches the directory (fs::write may replace the file); returns None if watching is unavailable.
+fn watch_state_file(state_file: &std::path::Path, ctx: egui::Context, dirty: Arc<AtomicBool>) -> Option<notify::RecommendedWatcher> {
+    use notify::{RecursiveMode, Watcher};
+
+    let file_name = state_file.file_name()?.to_owned();
+    let dir = state_file.parent()?;
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else { return };
+        if event.paths.iter().any(|p| p.file_name() == Some(file_name.as_os_str())) {
+            dirty.store(true, Ordering::SeqCst);
+            ctx.request_repaint();
+        }
+    })
+    .map_err(|e| tracing::warn!("state.json watcher unavailable, polling instead: {}", e))
+    .ok()?;
+
+    watcher
+        .watch(dir, RecursiveMode::NonRecursive)
+        .map_err(|e| tracing::warn!("Failed to watch {}: {} - polling instead", dir.display(), e))
+        .ok()?;
+    Some(watcher)
+}
+
+// Overlay-only helpers on the shared config (passed via --config to parent and children)
+impl ScreenshotConfig {
+    /// Write a finished capture in the effective format, paginated like capture_region_and_save.
+    /// Returns the first file plus every page written (empty = single file).
+    fn save_output(&self, img: &image::RgbaImage, monitor: usize) -> Result<(PathBuf, Vec<PathBuf>), Box<dyn std::error::Error>> {
+        let format = self.output_format();
+        let out_path = self.output_path(monitor, format.extension())?;
+        let pages = postprocess::save_paginated(img, &out_path, format, self.jpeg_background, self.page_height, self.page_overlap)?;
+        Ok((pages.first().cloned().unwrap_or(out_path), pages))
+    }
+
+    fn load(path: Option<&PathBuf>) -> Self {
+        let Some(path) = path else {
+            return Self::default();
+        };
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                tracing::warn!("Invalid config {}: {} - using defaults", path.display(), e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+}
+
+/// Command-line arguments
+/// Parent: [--only-monitor N] [--config PATH] [--live] [--picker] [--select x1,y1,x2,y2] [--on-release ACTION] • Child: --monitor N (same optional flags)
+#[derive(Default)]
+struct CliArgs {
+    monitor: Option<usize>,
+    only_monitor: Option<usize>,
+    config: Option<PathBuf>,
+    /// Start with a live (unfrozen) view; the frame is captured when the user presses F
+    live: bool,
+    /// Start in color picker mode
+    picker: bool,
+    /// Pre-seeded selection [min_x, min_y, max_x, max_y] in virtual coords (`--select x1,y1,x2,y2`)
+    select: Option<[f32; 4]>,
+    /// Pin mode: show an image as a floating window (`--pin <imagepath> <x> <y> <w> <h>`)
+    pin: Option<PinArgs>,
+    /// Pin mode: BCP-47 language for the text mode OCR (`--ocr-language en-US`; default = system)
+    ocr_language: Option<String>,
+    /// Where the parent writes the session outcome (OverlayResult JSON) after all children exit
+    result: Option<PathBuf>,
+    /// Session directory created by the parent (children only)
+    session: Option<PathBuf>,
+    /// What releasing a selection drag does (`--on-release save_file|copy_clipboard|stay_open`)
+    on_release: SelectionAction,
+}
+
+#[derive(Debug)]
+struct PinArgs {
+    image: PathBuf,
+    /// Window position and size in virtual desktop coordinates
+    rect: egui::Rect,
+}
+
+/// Fresh state.json for a new overlay session (mode flags come from the command line)
+fn initial_state(args: &CliArgs) -> SharedState {
+    SharedState {
+        frozen: !args.live,
+        picker_mode: args.picker,
+        selection_rect: args.select,
+        ..SharedState::default()
+    }
+}
+
+impl CliArgs {
+    fn parse() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut parsed = Self::default();
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", arg));
+            match arg.as_str() {
+                "--monitor" => parsed.monitor = Some(value()?.parse()?),
+                "--only-monitor" => parsed.only_monitor = Some(value()?.parse()?),
+                "--config" => parsed.config = Some(PathBuf::from(value()?)),
+                "--result" => parsed.result = Some(PathBuf::from(value()?)),
+                "--session" => parsed.session = Some(PathBuf::from(value()?)),
+                "--ocr-language" => parsed.ocr_language = Some(value()?),
+                "--on-release" => parsed.on_release = SelectionAction::from_arg(&value()?)?,
+                "--live" => parsed.live = true,
+                "--picker" => parsed.picker = true,
+                "--select" => {
+                    let coords: Vec<f32> = value()?
+                        .split(',')
+                        .map(|c| c.trim().parse())
+                        .collect::<Result<_, _>>()?;
+                    let rect: [f32; 4] = coords.try_into()
+                        .map_err(|_| "--select expects x1,y1,x2,y2")?;
+                    parsed.select = Some(rect);
+                }
+                "--pin" => {
+                    let image = PathBuf::from(value()?);
+                    let mut coords = [0.0f32; 4];
+                    for coord in &mut coords {
+                        *coord = value()?.parse()?;
+                    }
+                    let [x, y, w, h] = coords;
+                    parsed.pin = Some(PinArgs {
+                        image,
+                        rect: egui::Rect::from_min_size(egui::pos2(x, y), egui::vec2(w, h)),
+                    });
+                }
+                _ => return Err(format!("Unknown argument: {}", arg).into()),
+            }
+        }
+
+        Ok(parsed)
     }
 }
 
@@ -632,7 +2797,47 @@ struct MonitorMetadata {
     scale: f64,
 }
 
-fn capture_all_monitors() -> Vec<CapturedMonitor> {
+/// Physical top-left of each monitor inside a virtual-desktop bitmap (same order as `monitors`).
+///
+/// Walks columns of monitors (same logical x) left to right, advancing by the widest physical
+/// width in each column; within a column, monitors stack by their physical heights. Monitors
+/// alone in their column keep their logical y offset, scaled by their own DPI.
+fn physical_offsets(monitors: &[MonitorMetadata]) -> Vec<(u32, u32)> {
+    let origin_y = monitors.iter().map(|m| m.y).min().unwrap_or(0);
+    let physical_width = |m: &MonitorMetadata| (m.width as f64 * m.scale).round() as u32;
+    let physical_height = |m: &MonitorMetadata| (m.height as f64 * m.scale).round() as u32;
+
+    let mut columns: Vec<i32> = monitors.iter().map(|m| m.x).collect();
+    columns.sort_unstable();
+    columns.dedup();
+
+    let mut column_offset = std::collections::HashMap::new();
+    let mut x = 0u32;
+    for column in &columns {
+        column_offset.insert(*column, x);
+        x += monitors.iter()
+            .filter(|m| m.x == *column)
+            .map(physical_width)
+            .max()
+            .unwrap_or(0);
+    }
+
+    monitors.iter()
+        .map(|m| {
+            let above: Vec<&MonitorMetadata> = monitors.iter()
+                .filter(|other| other.x == m.x && other.y < m.y)
+                .collect();
+            let y = if above.is_empty() {
+                ((m.y - origin_y) as f64 * m.scale).round() as u32
+            } else {
+                above.iter().map(|other| physical_height(other)).sum()
+            };
+            (column_offset[&m.x], y)
+        })
+        .collect()
+}
+
+fn capture_all_monitors(config: &ScreenshotConfig, session_dir: &std::path::Path) -> Vec<CapturedMonitor> {
     let mut monitors = match Monitor::all() {
         Ok(monitors) => monitors,
         Err(e) => {
@@ -657,9 +2862,11 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
     }
 
     // CRITICAL FIX: Sort monitors by X position (left to right)
-    monitors.sort_by_key(|m| m.x().unwrap_or(0));
+    // (x, y) then OS name as tiebreak - must match screenshot_new::sorted_monitors exactly,
+    // otherwise --only-monitor N opens a different screen than the cursor detection picked
+    monitors.sort_by_key(|m| (m.x().unwrap_or(0), m.y().unwrap_or(0), m.name().unwrap_or_default()));
 
-    tracing::info!("Monitors AFTER sorting by X position:");
+    tracing::info!("Monitors AFTER sorting by (X, Y, name):");
     for (idx, monitor) in monitors.iter().enumerate() {
         tracing::info!(
             "  SORTED[{}]: pos=({}, {}), size={}×{}, scale={:.2}",
@@ -690,15 +2897,19 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
         })
         .collect();
 
-    let temp_dir = std::env::temp_dir().join("egui_overlay");
-    fs::create_dir_all(&temp_dir).ok();
+    let temp_dir = session_dir;
+    fs::create_dir_all(temp_dir).ok();
 
-    // Detect virtual desktop DPI scale (usually primary monitor at x=0, y=0)
-    let vd_scale = monitor_metadata.iter()
-        .find(|m| m.x == 0 && m.y == 0)
-        .map(|m| m.scale)
-        .unwrap_or(1.0);
-    tracing::info!("🌍 Virtual Desktop DPI scale detected: {:.2}", vd_scale);
+    // Where each monitor sits inside a virtual-desktop bitmap, at each monitor's own DPI
+    let physical_offsets = physical_offsets(&monitor_metadata);
+    for (meta, (px, py)) in monitor_metadata.iter().zip(&physical_offsets) {
+        tracing::info!(
+            "🌍 Monitor {}: physical offset in virtual desktop ({}, {}) size {}×{} (scale {:.2})",
+            meta.index, px, py,
+            (meta.width as f64 * meta.scale) as u32, (meta.height as f64 * meta.scale) as u32,
+            meta.scale
+        );
+    }
 
     // Pass 2: Capture and crop each monitor
     monitor_metadata
@@ -709,7 +2920,15 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
             let mon_y = meta.y;
             let mon_width = meta.width;
             let mon_height = meta.height;
-            let mon_scale = meta.scale;
+            let scale_override = config.monitor_scale_overrides.get(&index).copied();
+            let mon_scale = scale_override.unwrap_or(meta.scale);
+
+            if let Some(scale) = scale_override {
+                tracing::info!(
+                    "Monitor {}: ⚙️ Manual scale override {:.2} (detected {:.2}) - skipping virtual desktop detection",
+                    index, scale, meta.scale
+                );
+            }
 
             tracing::info!(
                 "Monitor {} metadata: logical {}×{} @ ({}, {}), scale {:.2}",
@@ -744,7 +2963,11 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
                     );
 
                     // Fixed threshold: Any capture >10% larger indicates virtual desktop
-                    let is_virtual_desktop = scale_x > 1.1 || scale_y > 1.1;
+                    // (a manual scale override means the user knows better - never crop)
+                    let is_virtual_desktop = scale_override.is_none() && (scale_x > 1.1 || scale_y > 1.1);
+
+                    // Which capture path produced final_image (logged to tell which one works on a machine)
+                    let mut backend = if is_virtual_desktop { CaptureBackend::XcapCropped } else { CaptureBackend::Xcap };
 
                     let final_image = if is_virtual_desktop {
                         tracing::warn!(
@@ -765,12 +2988,11 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
                             tracing::info!("Saved RAW virtual desktop to: {}", raw_path.display());
                         }
 
-                        // Calculate crop bounds - use VIRTUAL DESKTOP scale, not individual monitor scale!
-                        // Virtual desktop is rendered at primary monitor's DPI
-                        let crop_x = (mon_x as f64 * vd_scale) as u32;
-                        let crop_y = (mon_y as f64 * vd_scale) as u32;
-                        let crop_w = (mon_width as f64 * vd_scale) as u32;
-                        let crop_h = (mon_height as f64 * vd_scale) as u32;
+                        // Crop bounds: accumulated physical offset + this monitor's own DPI
+                        // (mixed-DPI setups are misaligned when one primary scale is assumed)
+                        let (crop_x, crop_y) = physical_offsets[index];
+                        let crop_w = (mon_width as f64 * mon_scale) as u32;
+                        let crop_h = (mon_height as f64 * mon_scale) as u32;
 
                         // Validate crop bounds
                         if crop_x + crop_w <= physical_width && crop_y + crop_h <= physical_height {
@@ -787,8 +3009,23 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
                                 "Monitor {}: ❌ Invalid crop bounds! ({}, {}) size {}×{} exceeds {}×{}",
                                 index, crop_x, crop_y, crop_w, crop_h, physical_width, physical_height
                             );
-                            tracing::warn!("Monitor {}: Using uncropped image as fallback", index);
-                            rgba_image
+                            // The uncropped virtual desktop would show the wrong area - retry per monitor
+                            match capture_with_screenshots(mon_x, mon_y) {
+                                Ok(image) => {
+                                    tracing::warn!(
+                                        "Monitor {}: 🔁 Recaptured with screenshots::Screen::capture_area ({}×{})",
+                                        index, image.width(), image.height()
+                                    );
+                                    backend = CaptureBackend::Screenshots;
+                                    image
+                                }
+                                Err(e) => {
+                                    tracing::error!("Monitor {}: ❌ screenshots fallback failed: {}", index, e);
+                                    tracing::warn!("Monitor {}: Using uncropped image as fallback", index);
+                                    backend = CaptureBackend::Xcap;
+                                    rgba_image
+                                }
+                            }
                         }
                     } else {
                         // No virtual desktop detected - use original image
@@ -812,7 +3049,7 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
                     tracing::info!(
                         "Monitor {}: ✅ Saved {} screenshot ({}×{}) to {}",
                         index,
-                        if is_virtual_desktop { "CROPPED" } else { "direct" },
+                        backend.label(),
                         final_image.width(), final_image.height(),
                         image_path.display()
                     );
@@ -836,11 +3073,74 @@ fn capture_all_monitors() -> Vec<CapturedMonitor> {
         .collect()
 }
 
-/// Calculate final texture size after GPU downscaling
-/// Returns (width, height) that will be used for the actual texture
-fn calculate_final_texture_size(monitor: &CapturedMonitor) -> (u32, u32) {
-    const MAX_TEXTURE_SIZE: u32 = 2048;
+/// Capture path that produced a monitor's screenshot
+#[derive(Clone, Copy, Debug)]
+enum CaptureBackend {
+    /// xcap image used as-is
+    Xcap,
+    /// xcap returned the whole virtual desktop, cropped to the monitor
+    XcapCropped,
+    /// Crop bounds failed validation, recaptured with the screenshots crate
+    Screenshots,
+}
+
+impl CaptureBackend {
+    fn label(&self) -> &'static str {
+        match self {
+            CaptureBackend::Xcap => "xcap direct",
+            CaptureBackend::XcapCropped => "xcap CROPPED",
+            CaptureBackend::Screenshots => "screenshots fallback",
+        }
+    }
+}
+
+/// Fallback for a monitor whose xcap virtual-desktop crop can't be trusted: capture just this
+/// screen with screenshots::Screen::capture_area (the path capture_region_and_save relies on).
+/// The result is copied into xcap's image type - the two crates may link different `image` versions.
+fn capture_with_screenshots(mon_x: i32, mon_y: i32) -> Result<image::RgbaImage, Box<dyn std::error::Error>> {
+    let screen = screenshots::Screen::from_point(mon_x, mon_y)?;
+    let info = screen.display_info;
+    let piece = screen.capture_area(0, 0, info.width, info.height)?;
+    let (width, height) = (piece.width(), piece.height());
+    image::RgbaImage::from_raw(width, height, piece.into_raw())
+        .ok_or_else(|| format!("screenshots returned a malformed {}×{} buffer", width, height).into())
+}
+
+/// Live capture of a virtual-desktop region (scroll capture frames), via the same screenshots
+/// capture_area path and region.rs stitching as capture_region_and_save, so a selection that
+/// spans monitors is captured whole.
+fn capture_live_region(rect: egui::Rect) -> Result<image::RgbaImage, String> {
+    let sel_x = rect.min.x.round() as i32;
+    let sel_y = rect.min.y.round() as i32;
+    let selection = region::Bounds::new(
+        sel_x,
+        sel_y,
+        (rect.max.x.round() as i32 - sel_x).max(0) as u32,
+        (rect.max.y.round() as i32 - sel_y).max(0) as u32,
+    );
+    let screens = screenshots::Screen::all().map_err(|e| e.to_string())?;
+    let bounds: Vec<region::Bounds> = screens.iter()
+        .map(|s| region::Bounds::new(s.display_info.x, s.display_info.y, s.display_info.width, s.display_info.height))
+        .collect();
+
+    let mut pieces = Vec::new();
+    for overlap in region::overlaps(selection, &bounds) {
+        let piece = screens[overlap.monitor]
+            .capture_area(overlap.rel_x, overlap.rel_y, overlap.area.width, overlap.area.height)
+            .map_err(|e| e.to_string())?;
+        // Copied into xcap's image type - the two crates may link different `image` versions
+        let (width, height) = (piece.width(), piece.height());
+        let piece = image::RgbaImage::from_raw(width, height, piece.into_raw())
+            .ok_or_else(|| format!("screenshots returned a malformed {}×{} buffer", width, height))?;
+        pieces.push((overlap, piece));
+    }
+
+    region::stitch(selection, pieces)
+}
 
+/// Window size for the legacy texture-sized geometry (monitor size capped to MAX_TEXTURE_SIZE)
+/// Returns (width, height) the pre-tiling downscaled texture used to have
+fn calculate_final_texture_size(monitor: &CapturedMonitor) -> (u32, u32) {
     let logical_width = monitor.width;
     let logical_height = monitor.height;
 
@@ -875,8 +3175,13 @@ fn calculate_virtual_desktop_bounds(monitors: &[CapturedMonitor]) -> egui::Rect
 }
 
 /// Child process: run overlay for specific monitor
-fn run_monitor_overlay(monitor_index: usize) -> Result<(), Box<dyn std::error::Error>> {
-    let temp_dir = std::env::temp_dir().join("egui_overlay");
+fn run_monitor_overlay(
+    monitor_index: usize,
+    config: ScreenshotConfig,
+    session_dir: PathBuf,
+    on_release: SelectionAction,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = &session_dir;
 
     // Load monitor data from temp files
     let monitors_json = fs::read_to_string(temp_dir.join("monitors.json"))?;
@@ -892,33 +3197,38 @@ fn run_monitor_overlay(monitor_index: usize) -> Result<(), Box<dyn std::error::E
     let monitor = monitors.get(monitor_index)
         .ok_or("Monitor index out of bounds")?
         .clone();
+    let all_monitors = monitors.clone();
 
-    let state_file = temp_dir.join("state.json");
+    // state.json was written fresh by the parent into this session's own directory,
+    // so there is no leftover should_close from an earlier capture to reset
 
-    // CRITICAL FIX: Reset state.json to prevent instant close from previous ESC
-    let fresh_state = SharedState::default();
-    if let Ok(json) = serde_json::to_string(&fresh_state) {
-        let _ = fs::write(&state_file, json);
-        tracing::info!("Child process: Reset state.json (cleared should_close flag)");
-    }
-
-    // CRITICAL FIX: Calculate final texture size BEFORE creating window
-    // This allows us to position and size the window correctly
+    // Texture size only sizes the window in the legacy texture geometry (and the diagnostics below)
     let (texture_width, texture_height) = calculate_final_texture_size(&monitor);
 
-    // Calculate scale ratio to adjust window position
+    // Texture-to-monitor ratio (diagnostics only)
     let scale_x = texture_width as f32 / monitor.width as f32;
-    let scale_y = texture_height as f32 / monitor.height as f32;
 
-    // Scale window position proportionally to texture size
-    let window_x = monitor.x as f32 * scale_x;
-    let window_y = monitor.y as f32 * scale_y;
+    let geometry = WindowGeometry::from_env();
+    let (window_pos, window_size) = match geometry {
+        // Decoupled: window always covers the whole monitor, egui scales the texture into it
+        WindowGeometry::Monitor => (
+            egui::pos2(monitor.x as f32, monitor.y as f32),
+            egui::vec2(monitor.width as f32, monitor.height as f32),
+        ),
+        // Legacy: window sized to the texture. The origin stays the monitor's own origin - scaling it
+        // pushed windows onto the neighbouring monitor (towards 0,0 for negative coordinates)
+        WindowGeometry::Texture => (
+            egui::pos2(monitor.x as f32, monitor.y as f32),
+            egui::vec2(texture_width as f32, texture_height as f32),
+        ),
+    };
 
     tracing::info!(
-        "Child process starting for monitor {} - Monitor: ({}, {}) {}×{} → Window: ({:.0}, {:.0}) {}×{} (scale: {:.3})",
+        "Child process starting for monitor {} - Monitor: ({}, {}) {}×{} → Window ({:?}): ({:.0}, {:.0}) {:.0}×{:.0}, texture {}×{} (scale: {:.3})",
         monitor.screen_index,
         monitor.x, monitor.y, monitor.width, monitor.height,
-        window_x, window_y, texture_width, texture_height,
+        geometry, window_pos.x, window_pos.y, window_size.x, window_size.y,
+        texture_width, texture_height,
         scale_x
     );
 
@@ -928,8 +3238,8 @@ fn run_monitor_overlay(monitor_index: usize) -> Result<(), Box<dyn std::error::E
             .with_decorations(false)
             .with_transparent(true)
             .with_always_on_top()
-            .with_position(egui::pos2(window_x, window_y))
-            .with_inner_size(egui::vec2(texture_width as f32, texture_height as f32))
+            .with_position(window_pos)
+            .with_inner_size(window_size)
             .with_resizable(false)
             .with_taskbar(false),
         ..Default::default()
@@ -941,7 +3251,251 @@ fn run_monitor_overlay(monitor_index: usize) -> Result<(), Box<dyn std::error::E
         &window_title,
         options,
         Box::new(move |cc| {
-            Ok(Box::new(OverlayApp::new(cc, monitor, state_file, vdb)))
+            // The native window exists by now - keep it out of every later capture
+            let excluded = exclude_own_windows_from_capture();
+            tracing::info!("Child process: excluded {} overlay window(s) from screen capture", excluded);
+            Ok(Box::new(OverlayApp::new(cc, monitor, all_monitors, config, session_dir, on_release, vdb, window_size)))
+        }),
+    )?;
+
+    // Let the shutter sound finish before the process exits
+    let pending_sound = PENDING_SOUND.lock().unwrap().take();
+    if let Some(sound) = pending_sound {
+        let _ = sound.join();
+    }
+
+    Ok(())
+}
+
+/// Borderless always-on-top window showing a pinned capture. Drag to move, Escape to close.
+/// T switches to text mode: the pin is OCR'd once and dragging selects words to copy instead.
+struct PinApp {
+    tiles: Vec<TextureTile>,
+    image_size: [usize; 2],
+    ocr_language: Option<String>,
+    /// Text mode (T): word boxes are shown and dragging selects text instead of moving the window
+    text_mode: bool,
+    ocr: PinOcr,
+    /// Selected word range in reading order (anchor, current) - indices into the OCR words
+    text_selection: Option<(usize, usize)>,
+}
+
+/// OCR of a pin, run on a worker thread the first time text mode is entered
+enum PinOcr {
+    /// Not requested yet (holds the pixels to recognize)
+    Idle(image::RgbaImage),
+    Running(std::sync::mpsc::Receiver<Result<Vec<ocr_engine::OcrWord>, String>>),
+    Done(Vec<ocr_engine::OcrWord>),
+    Failed(String),
+}
+
+impl PinApp {
+    fn start_ocr(&mut self, ctx: &egui::Context) {
+        if !matches!(self.ocr, PinOcr::Idle(_)) {
+            return;
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        if let PinOcr::Idle(rgba) = std::mem::replace(&mut self.ocr, PinOcr::Running(rx)) {
+            let language = self.ocr_language.clone();
+            let ctx = ctx.clone();
+            std::thread::spawn(move || {
+                let _ = tx.send(ocr_engine::recognize_words(rgba.as_raw(), rgba.width(), rgba.height(), language.as_deref()));
+                ctx.request_repaint();
+            });
+        }
+    }
+
+    fn poll_ocr(&mut self) {
+        let received = match &self.ocr {
+            PinOcr::Running(rx) => rx.try_recv(),
+            _ => return,
+        };
+        match received {
+            Ok(Ok(words)) => {
+                tracing::info!("🔤 Pin OCR found {} words", words.len());
+                self.ocr = PinOcr::Done(words);
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Pin OCR failed: {}", e);
+                self.ocr = PinOcr::Failed(e);
+            }
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.ocr = PinOcr::Failed("OCR worker exited".into());
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+        }
+    }
+
+    /// Copy the selected words to the clipboard
+    fn copy_text_selection(&self) {
+        let (PinOcr::Done(words), Some(range)) = (&self.ocr, self.text_selection) else {
+            return;
+        };
+        let text = words_text(words, range);
+        match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text.clone())) {
+            Ok(()) => tracing::info!("🔤 Copied {} chars from pin", text.chars().count()),
+            Err(e) => tracing::error!("Failed to copy pin text: {}", e),
+        }
+    }
+}
+
+/// Word closest to an image-pixel point (0 distance inside its box)
+fn nearest_word(words: &[ocr_engine::OcrWord], p: egui::Pos2) -> Option<usize> {
+    let distance = |w: &ocr_engine::OcrWord| {
+        let dx = (w.x - p.x).max(p.x - (w.x + w.width)).max(0.0);
+        let dy = (w.y - p.y).max(p.y - (w.y + w.height)).max(0.0);
+        dx.hypot(dy)
+    };
+    words.iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        .map(|(index, _)| index)
+}
+
+/// Words between two indices (either order), spaces within a line and newlines between lines
+fn words_text(words: &[ocr_engine::OcrWord], (anchor, current): (usize, usize)) -> String {
+    let (start, end) = (anchor.min(current), anchor.max(current).min(words.len().saturating_sub(1)));
+    let mut text = String::new();
+    let mut prev_line = None;
+    for word in words.get(start..=end).unwrap_or_default() {
+        if let Some(line) = prev_line {
+            text.push(if line == word.line { ' ' } else { '\n' });
+        }
+        text.push_str(&word.text);
+        prev_line = Some(word.line);
+    }
+    text
+}
+
+impl eframe::App for PinApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+        }
+
+        if ctx.input(|i| i.key_pressed(egui::Key::T)) {
+            self.text_mode = !self.text_mode;
+            self.text_selection = None;
+            if self.text_mode {
+                self.start_ocr(ctx);
+            }
+        }
+        self.poll_ocr();
+
+        // NOTE: egui-winit turns Ctrl+C into Event::Copy instead of a Key::C press
+        if self.text_mode && ctx.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy))) {
+            self.copy_text_selection();
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::none())
+            .show(ctx, |ui| {
+                let full_rect = ui.max_rect();
+                let response = ui.allocate_rect(full_rect, egui::Sense::drag());
+
+                // Same tile layout as the overlay, scaled from image pixels to the window
+                let scale_x = full_rect.width() / self.image_size[0] as f32;
+                let scale_y = full_rect.height() / self.image_size[1] as f32;
+                let to_image = |p: egui::Pos2| egui::pos2((p.x - full_rect.min.x) / scale_x, (p.y - full_rect.min.y) / scale_y);
+
+                if !self.text_mode {
+                    if response.drag_started_by(egui::PointerButton::Primary) {
+                        ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                    }
+                } else if let PinOcr::Done(words) = &self.ocr {
+                    let hovered = response.interact_pointer_pos().and_then(|p| nearest_word(words, to_image(p)));
+                    if response.drag_started_by(egui::PointerButton::Primary) {
+                        self.text_selection = hovered.map(|index| (index, index));
+                    } else if let (Some((anchor, _)), Some(current)) = (self.text_selection, hovered) {
+                        self.text_selection = Some((anchor, current));
+                    }
+                    if response.drag_stopped_by(egui::PointerButton::Primary) {
+                        self.copy_text_selection();
+                    }
+                }
+
+                let painter = ui.painter();
+                for tile in &self.tiles {
+                    let rect = egui::Rect::from_min_size(
+                        full_rect.min + egui::vec2(tile.x as f32 * scale_x, tile.y as f32 * scale_y),
+                        egui::vec2(tile.width as f32 * scale_x, tile.height as f32 * scale_y),
+                    );
+                    painter.image(
+                        tile.texture.id(),
+                        rect,
+                        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+                        egui::Color32::WHITE,
+                    );
+                }
+
+                if self.text_mode {
+                    let status = match &self.ocr {
+                        PinOcr::Idle(_) | PinOcr::Running(_) => "Recognizing text…".to_string(),
+                        PinOcr::Failed(e) => e.clone(),
+                        PinOcr::Done(words) => {
+                            let selected = self.text_selection.map(|(a, b)| a.min(b)..=a.max(b));
+                            for (index, word) in words.iter().enumerate() {
+                                let rect = egui::Rect::from_min_size(
+                                    full_rect.min + egui::vec2(word.x * scale_x, word.y * scale_y),
+                                    egui::vec2(word.width * scale_x, word.height * scale_y),
+                                );
+                                if selected.as_ref().is_some_and(|range| range.contains(&index)) {
+                                    painter.rect_filled(rect, 1.0, egui::Color32::from_rgba_unmultiplied(59, 130, 246, 90));
+                                } else {
+                                    painter.rect_stroke(rect, 1.0, egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(59, 130, 246, 140)));
+                                }
+                            }
+                            // OCR can split, merge or misread small/stylized text - the hint says so
+                            format!("{} words (OCR, may misread) • drag to select and copy • T to exit", words.len())
+                        }
+                    };
+                    let galley = painter.layout_no_wrap(status, egui::FontId::proportional(12.0), egui::Color32::WHITE);
+                    let label = egui::Rect::from_min_size(
+                        egui::pos2(full_rect.min.x + 4.0, full_rect.max.y - galley.size().y - 8.0),
+                        galley.size() + egui::vec2(8.0, 4.0),
+                    );
+                    painter.rect_filled(label, 3.0, egui::Color32::from_black_alpha(200));
+                    painter.galley(label.min + egui::vec2(4.0, 2.0), galley, egui::Color32::WHITE);
+                }
+
+                painter.rect_stroke(full_rect, 0.0, egui::Stroke::new(1.0, egui::Color32::from_rgb(59, 130, 246)));
+            });
+    }
+}
+
+fn run_pin_window(pin: &PinArgs, ocr_language: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let rgba = image::open(&pin.image)?.to_rgba8();
+    // Decoded into memory - the temp PNG isn't needed anymore
+    let _ = fs::remove_file(&pin.image);
+
+    tracing::info!("📌 Pin window at ({:.0}, {:.0}) {:.0}×{:.0}", pin.rect.min.x, pin.rect.min.y, pin.rect.width(), pin.rect.height());
+
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default()
+            .with_decorations(false)
+            .with_always_on_top()
+            .with_position(pin.rect.min)
+            .with_inner_size(pin.rect.size())
+            .with_resizable(false)
+            .with_taskbar(false),
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        "Screenshot Pin",
+        options,
+        Box::new(move |cc| {
+            let image = to_color_image(&rgba);
+            let image_size = image.size;
+            let tiles = load_texture_tiles(&cc.egui_ctx, &image, 0);
+            Ok(Box::new(PinApp {
+                tiles,
+                image_size,
+                ocr_language,
+                text_mode: false,
+                ocr: PinOcr::Idle(rgba),
+                text_selection: None,
+            }))
         }),
     )?;
 
@@ -957,38 +3511,67 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .init();
 
-    let args: Vec<String> = std::env::args().collect();
+    let args = CliArgs::parse()?;
+
+    // Pin window spawned by P - standalone, no capture/IPC
+    if let Some(pin) = &args.pin {
+        return run_pin_window(pin, args.ocr_language.clone());
+    }
+
+    let config = ScreenshotConfig::load(args.config.as_ref());
 
     // Check if we're a child process
-    if args.len() == 3 && args[1] == "--monitor" {
-        let monitor_index: usize = args[2].parse()?;
-        return run_monitor_overlay(monitor_index);
+    if let Some(monitor_index) = args.monitor {
+        let session_dir = args.session.clone().ok_or("--monitor requires --session <dir>")?;
+        return run_monitor_overlay(monitor_index, config, session_dir, args.on_release);
     }
 
     // Check for --only-monitor flag (F10: capture all but show only selected monitor)
-    let only_monitor: Option<usize> = if args.len() == 3 && args[1] == "--only-monitor" {
-        Some(args[2].parse()?)
-    } else {
-        None
-    };
+    let only_monitor = args.only_monitor;
 
     // ===== PARENT PROCESS MODE =====
 
+    // Mashing F10/F11 must not start parents that fight over the same state.json
+    let _instance_lock = match InstanceLock::acquire() {
+        Ok(Some(lock)) => Some(lock),
+        Ok(None) => {
+            tracing::info!("Another overlay is already open, exiting");
+            return Ok(());
+        }
+        Err(e) => {
+            tracing::warn!("Failed to acquire overlay lock ({}), continuing without it", e);
+            None
+        }
+    };
+
     if let Some(mon_idx) = only_monitor {
         tracing::info!("Parent process: starting screenshot overlay for Monitor {} ONLY", mon_idx);
     } else {
         tracing::info!("Parent process: starting multi-monitor screenshot overlay");
     }
 
+    let overlay_root = overlay_root();
+
+    // Leftovers from crashed previous runs (never cleaned up) - clear before capturing again
+    remove_stale_sessions(&overlay_root);
+
+    // Every capture gets its own directory, so nothing (state, PNGs) can bleed between sessions
+    let temp_dir = overlay_root.join(format!(
+        "{}_{}",
+        std::process::id(),
+        chrono::Local::now().format("%Y%m%d_%H%M%S_%3f")
+    ));
+    fs::create_dir_all(&temp_dir)?;
+    tracing::info!("Session directory: {}", temp_dir.display());
+
     // Capture all monitors (PNG screenshots already saved by capture function)
-    let monitors = capture_all_monitors();
+    // In --live mode this capture only provides geometry; children replace it when F is pressed
+    let monitors = capture_all_monitors(&config, &temp_dir);
 
     if monitors.is_empty() {
         return Err("No monitors captured".into());
     }
 
-    let temp_dir = std::env::temp_dir().join("egui_overlay");
-
     // Calculate virtual desktop bounds
     let virtual_desktop_bounds = calculate_virtual_desktop_bounds(&monitors);
     tracing::info!(
@@ -1019,7 +3602,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     fs::write(
         temp_dir.join("state.json"),
-        serde_json::to_string(&SharedState::default())?
+        serde_json::to_string(&initial_state(&args))?
     )?;
 
     tracing::info!("Saved metadata to temp directory");
@@ -1038,10 +3621,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     for index in monitors_to_launch {
         tracing::info!("Launching child process for monitor {}", index);
-        let child = Command::new(&exe_path)
-            .arg("--monitor")
-            .arg(index.to_string())
-            .spawn()?;
+        let mut command = Command::new(&exe_path);
+        command.arg("--monitor").arg(index.to_string());
+        command.arg("--session").arg(&temp_dir);
+        if let Some(config_path) = &args.config {
+            command.arg("--config").arg(config_path);
+        }
+        if args.live {
+            command.arg("--live");
+        }
+        if args.picker {
+            command.arg("--picker");
+        }
+        command.arg("--on-release").arg(args.on_release.as_arg());
+        if let Some([x1, y1, x2, y2]) = args.select {
+            command.arg("--select").arg(format!("{},{},{},{}", x1, y1, x2, y2));
+        }
+        let child = command.spawn()?;
         children.push(child);
     }
 
@@ -1063,15 +3659,159 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    // Cleanup temp directory (DISABLED for diagnostics)
-    // if let Err(e) = fs::remove_dir_all(&temp_dir) {
-    //     tracing::warn!("Failed to cleanup temp directory: {}", e);
-    // } else {
-    //     tracing::info!("Cleaned up temp directory");
-    // }
-    tracing::info!("Temp files preserved in: {}", temp_dir.display());
+    // Report the outcome to the launcher before the state file is cleaned up
+    if let Some(result_path) = &args.result {
+        let state = fs::read_to_string(temp_dir.join("state.json"))
+            .ok()
+            .and_then(|json| serde_json::from_str::<SharedState>(&json).ok())
+            .unwrap_or_default();
+        let result = OverlayResult::from_state(&state);
+        if let Err(e) = fs::write(result_path, serde_json::to_vec(&result)?) {
+            tracing::error!("Failed to write result to {}: {}", result_path.display(), e);
+        }
+    }
+
+    // Cleanup temp directory - only here in the parent, after every child has exited (wait loop above)
+    if debug_enabled() {
+        tracing::info!("Temp files preserved in: {} (AP30D_OVERLAY_DEBUG)", temp_dir.display());
+    } else {
+        remove_temp_dir(&temp_dir);
+        // Only succeeds once no other session directory is left
+        let _ = fs::remove_dir(&overlay_root);
+    }
 
     tracing::info!("Parent process exiting");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_state_writers_keep_versions_monotonic() {
+        const WRITES_PER_WRITER: u64 = 200;
+
+        let dir = std::env::temp_dir().join(format!("egui_overlay_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("state.json");
+        let _ = fs::remove_file(&path);
+
+        let read_version = |path: &std::path::Path| -> Option<u64> {
+            let json = match fs::read_to_string(path) {
+                Ok(json) => json,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+                Err(e) => panic!("Failed to read state: {}", e),
+            };
+            let state: SharedState = serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Reader saw a partial state file ({}): {:?}", e, json));
+            Some(state.version)
+        };
+
+        let done = Arc::new(AtomicBool::new(false));
+        let reader = std::thread::spawn({
+            let path = path.clone();
+            let done = done.clone();
+            move || {
+                let mut last = 0;
+                while !done.load(Ordering::SeqCst) {
+                    if let Some(version) = read_version(&path) {
+                        assert!(version >= last, "Version went backwards: {} after {}", version, last);
+                        last = version;
+                    }
+                }
+            }
+        });
+
+        let writers: Vec<_> = (0..2)
+            .map(|writer| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let mut state = SharedState { drag_owner: Some(writer), ..SharedState::default() };
+                    let mut written = 0;
+                    while written < WRITES_PER_WRITER {
+                        // Same read-then-bump as OverlayApp::write_state; a lost race returns false and retries
+                        state.version = read_version(&path).unwrap_or(0) + 1;
+                        if write_state_file(&path, &state).unwrap() {
+                            written += 1;
+                        }
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        done.store(true, Ordering::SeqCst);
+        reader.join().unwrap();
+
+        // Every accepted write bumped the version by exactly one
+        assert_eq!(read_version(&path), Some(2 * WRITES_PER_WRITER));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn state_write_check_lets_the_drag_owner_win_and_rejects_stale_writes() {
+        let on_disk = |version, drag_owner| SharedState { version, drag_owner, ..SharedState::default() };
+        let local = |version| SharedState { version, ..SharedState::default() };
+
+        // Owner wins: while monitor 1 drags, monitor 0 can't write, even with a newer version
+        assert_eq!(check_state_write(&on_disk(3, Some(1)), &local(5), 0), StateWriteCheck::OwnedByOther);
+        // ...but the owner itself writes over anything newer
+        assert_eq!(check_state_write(&on_disk(7, Some(1)), &local(5), 1), StateWriteCheck::Accept);
+
+        // Stale: someone wrote after we read - the change has to be re-applied on their state
+        assert_eq!(check_state_write(&on_disk(6, None), &local(5), 0), StateWriteCheck::Stale);
+        assert_eq!(check_state_write(&on_disk(5, None), &local(5), 0), StateWriteCheck::Accept);
+
+        // Closing always goes through
+        let closing = SharedState { should_close: true, ..local(0) };
+        assert_eq!(check_state_write(&on_disk(9, Some(1)), &closing, 0), StateWriteCheck::Accept);
+    }
+
+    #[test]
+    fn annotation_history_undoes_redoes_and_caps_depth() {
+        let rect = |x: f32| Annotation::Rect { rect: [x, 0.0, x + 10.0, 10.0] };
+        let mut state = SharedState::default();
+
+        for i in 0..ANNOTATION_HISTORY_DEPTH + 5 {
+            state.push_history(state.annotations.clone());
+            state.annotations.push(rect(i as f32));
+        }
+        assert_eq!(state.undo_stack.len(), ANNOTATION_HISTORY_DEPTH);
+
+        assert!(state.undo());
+        assert_eq!(state.annotations.len(), ANNOTATION_HISTORY_DEPTH + 4);
+        assert!(state.redo());
+        assert_eq!(state.annotations.len(), ANNOTATION_HISTORY_DEPTH + 5);
+        assert!(!state.redo());
+
+        // A new edit after undo drops the redo branch
+        assert!(state.undo());
+        state.push_history(state.annotations.clone());
+        state.annotations[0] = rect(100.0).translated(egui::vec2(5.0, 0.0));
+        assert!(!state.redo());
+        assert!(state.undo());
+        assert_eq!(state.annotations[0], rect(0.0));
+    }
+
+    #[test]
+    fn pin_text_selection_joins_words_by_line() {
+        let word = |text: &str, line: usize, x: f32| ocr_engine::OcrWord {
+            text: text.into(),
+            line,
+            x,
+            y: line as f32 * 20.0,
+            width: 30.0,
+            height: 12.0,
+        };
+        let words = [word("Hello", 0, 0.0), word("world", 0, 40.0), word("next", 1, 0.0)];
+
+        // Either drag direction selects the same reading-order range
+        assert_eq!(words_text(&words, (2, 0)), "Hello world\nnext");
+        assert_eq!(words_text(&words, (1, 1)), "world");
+        assert_eq!(nearest_word(&words, egui::pos2(45.0, 5.0)), Some(1));
+        assert_eq!(nearest_word(&words, egui::pos2(5.0, 60.0)), Some(2));
+    }
+}
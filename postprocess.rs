@@ -0,0 +1,265 @@
+// Also compiled into overlay_egui via #[path] - keep this file free of tauri/crate-specific imports.
+// `crate::image` is whichever image crate the including binary captures with
+// (screenshots' re-export in the app, xcap's in the overlay), so the two may differ in version.
+use crate::image::RgbaImage;
+use std::path::{Path, PathBuf};
+
+/// Single post-processing step of a named preset
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PostProcessOp {
+    /// Downscale to at most this width, keeping aspect ratio
+    Resize { max_width: u32 },
+    RoundCorners { radius: u32 },
+    /// Save in this encoding instead of the configured one (the last Format step wins)
+    Format { format: ScreenshotFormat },
+    /// Blurred drop shadow; the canvas grows by radius + offset so nothing is clipped
+    Shadow { radius: u32, offset: [i32; 2], opacity: u8 },
+    /// Image (e.g. a logo PNG) blended into the bottom-right corner
+    Watermark { path: PathBuf, opacity: u8, margin: u32 },
+}
+
+/// Output encoding for saved screenshots
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScreenshotFormat {
+    Png,
+    /// Alpha is flattened onto `jpeg_background`
+    Jpeg { quality: u8 },
+    /// Lossless only - the image crate has no lossy WebP encoder, so there is no quality setting.
+    /// Older configs with {"kind": "web_p", "quality": N} still load; the quality is dropped.
+    WebP,
+}
+
+impl ScreenshotFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ScreenshotFormat::Png => "png",
+            ScreenshotFormat::Jpeg { .. } => "jpg",
+            ScreenshotFormat::WebP => "webp",
+        }
+    }
+}
+
+/// Antialiased transparent corners: pixels outside the quarter circle lose alpha by their coverage
+pub fn round_corners(img: &mut RgbaImage, radius: u32) {
+    let (w, h) = img.dimensions();
+    let r = radius.min(w / 2).min(h / 2);
+    if r == 0 {
+        return;
+    }
+    let rf = r as f32;
+
+    for cy in 0..r {
+        for cx in 0..r {
+            // distance of the pixel center from the top-left corner circle's center
+            let dx = rf - (cx as f32 + 0.5);
+            let dy = rf - (cy as f32 + 0.5);
+            let coverage = (rf - (dx * dx + dy * dy).sqrt() + 0.5).clamp(0.0, 1.0);
+            if coverage >= 1.0 {
+                continue;
+            }
+
+            // same offset in all four corners
+            for (x, y) in [(cx, cy), (w - 1 - cx, cy), (cx, h - 1 - cy), (w - 1 - cx, h - 1 - cy)] {
+                let px = img.get_pixel_mut(x, y);
+                px[3] = (px[3] as f32 * coverage).round() as u8;
+            }
+        }
+    }
+}
+
+/// Trim uniform margins: edge rows/columns whose every pixel is within `tolerance` per channel
+/// of the background color (the corner pixel). A fully uniform image is returned unchanged
+/// instead of an empty one.
+pub fn autocrop(img: &RgbaImage, tolerance: u8) -> RgbaImage {
+    use crate::image::Rgba;
+
+    let (w, h) = img.dimensions();
+    if w == 0 || h == 0 {
+        return img.clone();
+    }
+
+    let similar = |a: &Rgba<u8>, b: &Rgba<u8>| {
+        a.0.iter().zip(b.0.iter()).all(|(x, y)| x.abs_diff(*y) <= tolerance)
+    };
+    // top/left margins compare against the top-left corner, bottom/right against the bottom-right one
+    let top_left = *img.get_pixel(0, 0);
+    let bottom_right = *img.get_pixel(w - 1, h - 1);
+    let row_is = |y: u32, bg| (0..w).all(|x| similar(img.get_pixel(x, y), bg));
+    let col_is = |x: u32, top: u32, bottom: u32, bg| (top..bottom).all(|y| similar(img.get_pixel(x, y), bg));
+
+    let mut top = 0;
+    while top < h && row_is(top, &top_left) {
+        top += 1;
+    }
+    if top == h {
+        return img.clone(); // the whole image is uniform - nothing would be left
+    }
+    let mut bottom = h;
+    while bottom > top && row_is(bottom - 1, &bottom_right) {
+        bottom -= 1;
+    }
+    let mut left = 0;
+    while left < w && col_is(left, top, bottom, &top_left) {
+        left += 1;
+    }
+    let mut right = w;
+    while right > left && col_is(right - 1, top, bottom, &bottom_right) {
+        right -= 1;
+    }
+    if left == right {
+        return img.clone();
+    }
+
+    crate::image::imageops::crop_imm(img, left, top, right - left, bottom - top).to_image()
+}
+
+/// Fixed-height pages with `overlap` rows repeated at the top of each following page.
+/// page_height == 0 = one page; overlap >= page_height is clamped so every page advances by at least 1px.
+pub fn split_into_pages(img: &RgbaImage, page_height: u32, overlap: u32) -> Vec<RgbaImage> {
+    let (w, h) = img.dimensions();
+    if page_height == 0 || h <= page_height {
+        return vec![img.clone()];
+    }
+    let step = page_height - overlap.min(page_height - 1);
+
+    let mut pages = Vec::new();
+    let mut y = 0;
+    loop {
+        let page_h = page_height.min(h - y);
+        pages.push(crate::image::imageops::crop_imm(img, 0, y, w, page_h).to_image());
+        if y + page_h >= h {
+            break;
+        }
+        y += step;
+    }
+    pages
+}
+
+/// Drop shadow under the image: its alpha mask, darkened, blurred and shifted by `offset`.
+/// The canvas grows by radius + offset, so rounded corners get a rounded shadow.
+pub fn drop_shadow(img: &RgbaImage, radius: u32, offset: [i32; 2], opacity: u8) -> RgbaImage {
+    use crate::image::{imageops, Rgba};
+
+    let (w, h) = img.dimensions();
+    let [dx, dy] = offset;
+    let pad_left = radius + (-dx).max(0) as u32;
+    let pad_top = radius + (-dy).max(0) as u32;
+    let canvas_w = w + 2 * radius + dx.unsigned_abs();
+    let canvas_h = h + 2 * radius + dy.unsigned_abs();
+
+    let mut shadow = RgbaImage::new(canvas_w, canvas_h);
+    let (shadow_x, shadow_y) = ((pad_left as i32 + dx) as u32, (pad_top as i32 + dy) as u32);
+    for (x, y, px) in img.enumerate_pixels() {
+        let alpha = (px[3] as u32 * opacity as u32 / 255) as u8;
+        shadow.put_pixel(shadow_x + x, shadow_y + y, Rgba([0, 0, 0, alpha]));
+    }
+    let mut canvas = if radius > 0 {
+        imageops::blur(&shadow, radius as f32 / 2.0)
+    } else {
+        shadow
+    };
+    imageops::overlay(&mut canvas, img, pad_left as i64, pad_top as i64);
+    canvas
+}
+
+/// Image file blended into the bottom-right corner; an unreadable or oversized file is skipped
+pub fn watermark(img: &mut RgbaImage, path: &Path, opacity: u8, margin: u32) {
+    let mut mark = match crate::image::open(path) {
+        Ok(mark) => mark.to_rgba8(),
+        Err(e) => {
+            tracing::warn!("⚠️ Watermark {} unreadable, skipping: {}", path.display(), e);
+            return;
+        }
+    };
+    let (w, h) = img.dimensions();
+    if mark.width() + margin > w || mark.height() + margin > h {
+        tracing::warn!("⚠️ Watermark {}×{} doesn't fit a {}×{} capture, skipping", mark.width(), mark.height(), w, h);
+        return;
+    }
+
+    for px in mark.pixels_mut() {
+        px[3] = (px[3] as u32 * opacity as u32 / 255) as u8;
+    }
+    let x = w - mark.width() - margin;
+    let y = h - mark.height() - margin;
+    crate::image::imageops::overlay(img, &mark, x as i64, y as i64);
+}
+
+/// Format forced by a preset (its last Format step), None = keep the configured one
+pub fn preset_format(ops: &[PostProcessOp]) -> Option<ScreenshotFormat> {
+    ops.iter().rev().find_map(|op| match op {
+        PostProcessOp::Format { format } => Some(*format),
+        _ => None,
+    })
+}
+
+/// Run a preset's steps in order (Format leaves the pixels alone - see preset_format)
+pub fn apply_post_process(mut img: RgbaImage, ops: &[PostProcessOp]) -> RgbaImage {
+    use crate::image::imageops::{self, FilterType};
+
+    for op in ops {
+        match op {
+            PostProcessOp::Resize { max_width } => {
+                if img.width() > *max_width && *max_width > 0 {
+                    let height = (img.height() as f64 * *max_width as f64 / img.width() as f64).round().max(1.0) as u32;
+                    img = imageops::resize(&img, *max_width, height, FilterType::Lanczos3);
+                }
+            }
+            PostProcessOp::RoundCorners { radius } => round_corners(&mut img, *radius),
+            PostProcessOp::Format { .. } => {}
+            PostProcessOp::Shadow { radius, offset, opacity } => img = drop_shadow(&img, *radius, *offset, *opacity),
+            PostProcessOp::Watermark { path, opacity, margin } => watermark(&mut img, path, *opacity, *margin),
+        }
+    }
+    img
+}
+
+/// Encode in `format` (JPEG: transparency flattened onto `background`)
+pub fn save_image(img: &RgbaImage, path: &Path, format: ScreenshotFormat, background: [u8; 3]) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::image::{codecs::jpeg::JpegEncoder, ImageFormat, Rgb, RgbImage};
+
+    match format {
+        ScreenshotFormat::Png => img.save_with_format(path, ImageFormat::Png)?,
+        ScreenshotFormat::Jpeg { quality } => {
+            let flattened = RgbImage::from_fn(img.width(), img.height(), |x, y| {
+                let [r, g, b, a] = img.get_pixel(x, y).0;
+                let alpha = a as u32;
+                let blend = |c: u8, bg: u8| ((c as u32 * alpha + bg as u32 * (255 - alpha)) / 255) as u8;
+                Rgb([blend(r, background[0]), blend(g, background[1]), blend(b, background[2])])
+            });
+            let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+            JpegEncoder::new_with_quality(file, quality.clamp(1, 100)).encode_image(&flattened)?;
+        }
+        ScreenshotFormat::WebP => img.save_with_format(path, ImageFormat::WebP)?,
+    }
+    Ok(())
+}
+
+/// Save to `path`, or - when taller than `page_height` - as stem_p01.ext, stem_p02.ext... next to it.
+/// Returns every page written (empty = a single file at `path`).
+pub fn save_paginated(
+    img: &RgbaImage,
+    path: &Path,
+    format: ScreenshotFormat,
+    background: [u8; 3],
+    page_height: Option<u32>,
+    overlap: u32,
+) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let Some(page_height) = page_height.filter(|&page_height| img.height() > page_height) else {
+        save_image(img, path, format, background)?;
+        return Ok(Vec::new());
+    };
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let pages = split_into_pages(img, page_height, overlap);
+    let mut page_paths = Vec::with_capacity(pages.len());
+    for (i, page) in pages.iter().enumerate() {
+        let page_path = path.with_file_name(format!("{}_p{:02}.{}", stem, i + 1, format.extension()));
+        save_image(page, &page_path, format, background)?;
+        page_paths.push(page_path);
+    }
+    tracing::info!("📄 Split {}px capture into {} page(s) of {}px", img.height(), pages.len(), page_height);
+    Ok(page_paths)
+}
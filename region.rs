@@ -0,0 +1,104 @@
+// Also compiled into overlay_egui via #[path] - keep this file free of tauri/crate-specific imports.
+// `crate::image` is the including binary's image crate (see postprocess.rs).
+use crate::image::{imageops, RgbaImage};
+
+/// Rectangle in virtual-desktop coordinates (a monitor or a selection)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Bounds {
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    fn right(&self) -> i32 {
+        self.x + self.width as i32
+    }
+
+    fn bottom(&self) -> i32 {
+        self.y + self.height as i32
+    }
+
+    pub fn intersect(&self, other: &Bounds) -> Option<Bounds> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let x2 = self.right().min(other.right());
+        let y2 = self.bottom().min(other.bottom());
+        (x2 > x && y2 > y).then(|| Bounds::new(x, y, (x2 - x) as u32, (y2 - y) as u32))
+    }
+}
+
+/// Part of a selection lying on one monitor
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    /// Index into the monitor slice passed to `overlaps`
+    pub monitor: usize,
+    /// Intersection in virtual-desktop coords
+    pub area: Bounds,
+    /// `area.x/y` relative to the monitor's top-left corner (what capture_area expects)
+    pub rel_x: i32,
+    pub rel_y: i32,
+}
+
+/// Intersections of the selection with every monitor it touches
+pub fn overlaps(selection: Bounds, monitors: &[Bounds]) -> Vec<Overlap> {
+    monitors.iter()
+        .enumerate()
+        .filter_map(|(monitor, m)| {
+            let area = selection.intersect(m)?;
+            Some(Overlap { monitor, area, rel_x: area.x - m.x, rel_y: area.y - m.y })
+        })
+        .collect()
+}
+
+/// Cut an overlap out of a whole-monitor capture. The capture may be at the monitor's physical
+/// resolution, so virtual coords are mapped with that monitor's own scale.
+/// Overlay only - the app captures each overlap directly with capture_area.
+#[allow(dead_code)]
+pub fn crop_overlap(capture: &RgbaImage, monitor: Bounds, overlap: &Overlap) -> RgbaImage {
+    let scale_x = capture.width() as f64 / monitor.width.max(1) as f64;
+    let scale_y = capture.height() as f64 / monitor.height.max(1) as f64;
+    let crop_x = ((overlap.rel_x as f64 * scale_x) as u32).min(capture.width());
+    let crop_y = ((overlap.rel_y as f64 * scale_y) as u32).min(capture.height());
+    let crop_w = ((overlap.area.width as f64 * scale_x).round() as u32).min(capture.width() - crop_x);
+    let crop_h = ((overlap.area.height as f64 * scale_y).round() as u32).min(capture.height() - crop_y);
+    imageops::crop_imm(capture, crop_x, crop_y, crop_w, crop_h).to_image()
+}
+
+/// Stitch per-monitor pieces into one image of the selection.
+///
+/// Pieces may come at different DPI (frozen overlay PNGs or live capture_area results alike);
+/// the output uses the highest scale among them and every other piece is resized to it, so the
+/// overlay and the headless capture produce the same pixels for the same selection.
+pub fn stitch(selection: Bounds, pieces: Vec<(Overlap, RgbaImage)>) -> Result<RgbaImage, String> {
+    if pieces.is_empty() {
+        return Err("Selection does not intersect any monitor".into());
+    }
+
+    let scale = pieces.iter()
+        .map(|(overlap, piece)| piece.width() as f64 / overlap.area.width.max(1) as f64)
+        .fold(0.0_f64, f64::max);
+
+    let out_w = (selection.width as f64 * scale).round() as u32;
+    let out_h = (selection.height as f64 * scale).round() as u32;
+    let mut out = RgbaImage::new(out_w, out_h);
+
+    for (overlap, mut piece) in pieces {
+        let dst_w = (overlap.area.width as f64 * scale).round() as u32;
+        let dst_h = (overlap.area.height as f64 * scale).round() as u32;
+        if piece.width() != dst_w || piece.height() != dst_h {
+            piece = imageops::resize(&piece, dst_w, dst_h, imageops::FilterType::Lanczos3);
+        }
+
+        let dx = ((overlap.area.x - selection.x) as f64 * scale).round() as i64;
+        let dy = ((overlap.area.y - selection.y) as f64 * scale).round() as i64;
+        imageops::replace(&mut out, &piece, dx, dy);
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,323 @@
+use tauri::{AppHandle, Manager};
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+// Shared with overlay_egui through postprocess.rs / screenshot_settings.rs
+pub use crate::postprocess::{PostProcessOp, ScreenshotFormat};
+pub use crate::screenshot_settings::{PostCaptureAction, ScreenshotConfig, SelectionAction};
+
+pub fn config_path(app: &AppHandle) -> PathBuf {
+    app.path().app_data_dir().unwrap().join("screenshot-config.json")
+}
+
+/// Load config, falling back to defaults when the file is missing or unreadable
+pub fn load_config(app: &AppHandle) -> ScreenshotConfig {
+    fs::read_to_string(config_path(app))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_config(app: &AppHandle, config: &ScreenshotConfig) -> std::result::Result<(), String> {
+    let path = config_path(app);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let json = serde_json::to_vec_pretty(config)
+        .map_err(|e| format!("Failed to serialize screenshot config: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[tauri::command]
+pub fn get_screenshot_config(app: AppHandle) -> ScreenshotConfig {
+    load_config(&app)
+}
+
+/// Enable/disable rounded corners on saved screenshots
+#[tauri::command]
+pub fn set_rounded_corners(app: AppHandle, enabled: bool, radius: u32) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.round_corners = enabled;
+    config.corner_radius = radius;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Rounded corners: {} (radius {}px)", enabled, radius);
+    Ok(())
+}
+
+/// Configure splitting of tall captures into pages (page_height = None disables it)
+#[tauri::command]
+pub fn set_pagination(app: AppHandle, page_height: Option<u32>, overlap: u32) -> std::result::Result<(), String> {
+    if let Some(height) = page_height {
+        if overlap >= height {
+            return Err(format!("Overlap ({}px) must be smaller than page height ({}px)", overlap, height));
+        }
+    }
+    let mut config = load_config(&app);
+    config.page_height = page_height;
+    config.page_overlap = overlap;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Pagination: {:?} (overlap {}px)", page_height, overlap);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn save_preset(app: AppHandle, name: String, ops: Vec<PostProcessOp>) -> std::result::Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Preset name cannot be empty".into());
+    }
+    let mut config = load_config(&app);
+    config.presets.insert(name.clone(), ops);
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Saved post-processing preset '{}'", name);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_presets(app: AppHandle) -> BTreeMap<String, Vec<PostProcessOp>> {
+    load_config(&app).presets
+}
+
+/// Delete a preset (also clears it as the post-capture preset if it was active)
+#[tauri::command]
+pub fn delete_preset(app: AppHandle, name: String) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    if config.presets.remove(&name).is_none() {
+        return Err(format!("Preset '{}' not found", name));
+    }
+    if config.post_capture_preset.as_deref() == Some(name.as_str()) {
+        config.post_capture_preset = None;
+    }
+    save_config(&app, &config)
+}
+
+/// Choose the preset applied after every capture (None = no post-processing)
+#[tauri::command]
+pub fn set_post_capture_preset(app: AppHandle, name: Option<String>) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    if let Some(name) = &name {
+        if !config.presets.contains_key(name) {
+            return Err(format!("Preset '{}' not found", name));
+        }
+    }
+    config.post_capture_preset = name;
+    save_config(&app, &config)
+}
+
+/// Force the DPI scale used for one monitor's capture (None = auto-detect).
+///
+/// Escape hatch for setups where the virtual-desktop detection in overlay_egui misfires
+/// (black, shifted or zoomed-in overlay on one screen): with an override set, the capture is
+/// used as-is with no detection and no cropping. Use the monitor's Windows display scale (e.g. 1.5).
+#[tauri::command]
+pub fn set_monitor_scale_override(app: AppHandle, index: usize, scale: Option<f64>) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    match scale {
+        Some(scale) if !(0.5..=5.0).contains(&scale) => {
+            return Err(format!("Scale {:.2} out of range (0.5 - 5.0)", scale));
+        }
+        Some(scale) => {
+            config.monitor_scale_overrides.insert(index, scale);
+        }
+        None => {
+            config.monitor_scale_overrides.remove(&index);
+        }
+    }
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Monitor {} scale override: {:?}", index, scale);
+    Ok(())
+}
+
+/// Current output directory (resolved, so the settings page shows the real default) and template
+#[derive(serde::Serialize)]
+pub struct OutputSettings {
+    pub output_dir: PathBuf,
+    pub filename_template: String,
+}
+
+#[tauri::command]
+pub fn get_screenshot_output(app: AppHandle) -> OutputSettings {
+    let config = load_config(&app);
+    OutputSettings {
+        output_dir: config.resolved_output_dir(),
+        filename_template: config.filename_template,
+    }
+}
+
+/// Set the screenshot folder (created if missing)
+#[tauri::command]
+pub fn set_screenshot_output_dir(app: AppHandle, path: String) -> std::result::Result<(), String> {
+    let dir = PathBuf::from(path.trim());
+    if dir.as_os_str().is_empty() {
+        return Err("Output directory cannot be empty".into());
+    }
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let mut config = load_config(&app);
+    config.output_dir = Some(dir.clone());
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Screenshot output dir: {}", dir.display());
+    Ok(())
+}
+
+/// Set the file name template (without extension), e.g. "shot_{date}_{counter}"
+#[tauri::command]
+pub fn set_filename_template(app: AppHandle, template: String) -> std::result::Result<(), String> {
+    let template = template.trim().to_string();
+    if template.is_empty() {
+        return Err("Filename template cannot be empty".into());
+    }
+    if template.contains(['/', '\\', ':', '*', '?', '"', '<', '>', '|']) {
+        return Err(format!("Filename template contains invalid characters: {}", template));
+    }
+
+    let mut config = load_config(&app);
+    config.filename_template = template;
+    save_config(&app, &config)
+}
+
+/// Cap for the longest side of clipboard captures (None = keep full size)
+#[tauri::command]
+pub fn set_max_output_dimension(app: AppHandle, max_dimension: Option<u32>) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.max_output_dimension = max_dimension;
+    save_config(&app, &config)
+}
+
+/// Choose PNG/JPEG/WebP for saved screenshots (background = JPEG alpha flatten color).
+/// Only JPEG takes a quality; WebP is always lossless.
+#[tauri::command]
+pub fn set_screenshot_format(app: AppHandle, format: ScreenshotFormat, background: Option<[u8; 3]>) -> std::result::Result<(), String> {
+    if let ScreenshotFormat::Jpeg { quality } = format {
+        if !(1..=100).contains(&quality) {
+            return Err(format!("Quality must be 1-100, got {}", quality));
+        }
+    }
+    let mut config = load_config(&app);
+    config.format = format;
+    if let Some(background) = background {
+        config.jpeg_background = background;
+    }
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Screenshot format: {:?}", format);
+    Ok(())
+}
+
+/// Color/opacity of the overlay's composition guides (RGBA)
+#[tauri::command]
+pub fn set_guide_color(app: AppHandle, color: [u8; 4]) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.guide_color = color;
+    save_config(&app, &config)
+}
+
+/// Enable/disable trimming of uniform borders after capture
+#[tauri::command]
+pub fn set_auto_crop(app: AppHandle, enabled: bool, tolerance: u8) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.auto_crop = enabled;
+    config.auto_crop_tolerance = tolerance;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Auto-crop: {} (tolerance {})", enabled, tolerance);
+    Ok(())
+}
+
+/// Include the drop shadow / invisible resize border in window captures (default: tight DWM bounds)
+#[tauri::command]
+pub fn set_window_capture_shadow(app: AppHandle, include_shadow: bool) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.window_shadow = include_shadow;
+    save_config(&app, &config)
+}
+
+/// Capture sound on/off, volume (0.0 - 1.0) and optional custom sound file
+#[tauri::command]
+pub fn set_capture_sound(app: AppHandle, enabled: bool, volume: f32, file: Option<String>) -> std::result::Result<(), String> {
+    if !(0.0..=1.0).contains(&volume) {
+        return Err(format!("Volume must be 0.0 - 1.0, got {}", volume));
+    }
+    let file = file.map(PathBuf::from);
+    if let Some(path) = &file {
+        if !path.is_file() {
+            return Err(format!("Sound file not found: {}", path.display()));
+        }
+    }
+
+    let mut config = load_config(&app);
+    config.capture_sound = enabled;
+    config.capture_sound_volume = volume;
+    config.capture_sound_file = file;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Capture sound: {} (volume {:.2})", enabled, volume);
+    Ok(())
+}
+
+/// Configure "capture → shareable link": synced folder + optional public URL prefix (folder None = off)
+#[tauri::command]
+pub fn set_share_target(app: AppHandle, folder: Option<String>, url_prefix: Option<String>) -> std::result::Result<(), String> {
+    let folder = folder.map(|f| PathBuf::from(f.trim())).filter(|f| !f.as_os_str().is_empty());
+    if let Some(dir) = &folder {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    let url_prefix = url_prefix.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+
+    let mut config = load_config(&app);
+    config.share_folder = folder;
+    config.share_url_prefix = url_prefix;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Share folder: {:?} (URL prefix: {:?})", config.share_folder, config.share_url_prefix);
+    Ok(())
+}
+
+/// F10 opens on the last used monitor instead of the one under the cursor
+#[tauri::command]
+pub fn set_remember_last_monitor(app: AppHandle, enabled: bool) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.remember_last_monitor = enabled;
+    save_config(&app, &config)
+}
+
+/// Minimum overlay selection size and optional [width, height] cap (None = unlimited)
+#[tauri::command]
+pub fn set_selection_size_limits(app: AppHandle, min: u32, max: Option<[u32; 2]>) -> std::result::Result<(), String> {
+    if min == 0 {
+        return Err("Minimum selection size must be at least 1px".into());
+    }
+    if let Some([max_w, max_h]) = max {
+        if max_w < min || max_h < min {
+            return Err(format!("Maximum selection {}×{} is smaller than the {}px minimum", max_w, max_h, min));
+        }
+    }
+    let mut config = load_config(&app);
+    config.min_selection_size = min;
+    config.max_selection_size = max;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Selection size: min {}px, max {:?}", min, max);
+    Ok(())
+}
+
+/// What releasing an overlay selection does: save_file, copy_clipboard or stay_open
+#[tauri::command]
+pub fn set_default_action(app: AppHandle, action: SelectionAction) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.default_action = action;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Overlay release action: {:?}", action);
+    Ok(())
+}
+
+/// After a region capture is saved: none, open_in_default_app or reveal_in_explorer
+#[tauri::command]
+pub fn set_post_capture_action(app: AppHandle, action: PostCaptureAction) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.post_capture_action = action;
+    save_config(&app, &config)?;
+    tracing::info!("⚙️ Post-capture action: {:?}", action);
+    Ok(())
+}
+
+/// Open the overlay with the previous confirmed selection already drawn (Enter to repeat the capture)
+#[tauri::command]
+pub fn set_reuse_last_selection(app: AppHandle, enabled: bool) -> std::result::Result<(), String> {
+    let mut config = load_config(&app);
+    config.reuse_last_selection = enabled;
+    save_config(&app, &config)
+}
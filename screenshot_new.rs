@@ -1,6 +1,9 @@
 use tauri::{AppHandle, Manager, Emitter};
-use std::{fs, path::PathBuf, process::Command};
+use std::{fs, path::{Path, PathBuf}, process::Command};
 use xcap::Monitor;
+use crate::postprocess::{autocrop, save_paginated};
+use crate::region::{self, Bounds};
+use crate::screenshot_config::{self, PostCaptureAction};
 
 // Legacy imports for dead_code functions (will be removed in future)
 #[allow(unused_imports)]
@@ -11,7 +14,7 @@ type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
 // Windows-specific cursor position detection
 #[cfg(windows)]
-fn get_cursor_position() -> std::result::Result<(i32, i32), String> {
+pub(crate) fn get_cursor_position() -> std::result::Result<(i32, i32), String> {
     use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
     use windows::Win32::Foundation::POINT;
 
@@ -26,40 +29,184 @@ fn get_cursor_position() -> std::result::Result<(i32, i32), String> {
 }
 
 #[cfg(not(windows))]
-fn get_cursor_position() -> std::result::Result<(i32, i32), String> {
+pub(crate) fn get_cursor_position() -> std::result::Result<(i32, i32), String> {
     Err("Cursor detection only supported on Windows".into())
 }
 
-/// Detect which monitor contains the cursor
-fn detect_monitor_at_cursor() -> std::result::Result<usize, String> {
-    let cursor_pos = get_cursor_position()?;
+/// Window bounds (x, y, w, h): DWM extended frame bounds (tight, what the user sees) or the raw
+/// GetWindowRect, which also covers the invisible resize border and drop shadow
+#[cfg(windows)]
+pub(crate) fn window_bounds(
+    hwnd: windows::Win32::Foundation::HWND,
+    include_shadow: bool,
+) -> std::result::Result<(i32, i32, i32, i32), String> {
+    use windows::Win32::Foundation::RECT;
+    use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
+
+    let mut rect = RECT::default();
+    unsafe {
+        let tight = !include_shadow && DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_EXTENDED_FRAME_BOUNDS,
+            &mut rect as *mut RECT as *mut std::ffi::c_void,
+            std::mem::size_of::<RECT>() as u32,
+        ).is_ok();
+
+        // DWM off (or shadow requested) → fall back to the raw window rect
+        if !tight {
+            GetWindowRect(hwnd, &mut rect).map_err(|e| format!("GetWindowRect failed: {}", e))?;
+        }
+    }
+    Ok((rect.left, rect.top, rect.right - rect.left, rect.bottom - rect.top))
+}
+
+/// Bounds of the top-level window under a screen point
+#[cfg(windows)]
+fn window_bounds_at(x: i32, y: i32, include_shadow: bool) -> std::result::Result<(i32, i32, i32, i32), String> {
+    use windows::Win32::Foundation::POINT;
+    use windows::Win32::UI::WindowsAndMessaging::{GetAncestor, WindowFromPoint, GA_ROOT};
+
+    let hwnd = unsafe { GetAncestor(WindowFromPoint(POINT { x, y }), GA_ROOT) };
+    if hwnd.0.is_null() {
+        return Err(format!("No window at ({}, {})", x, y));
+    }
+    window_bounds(hwnd, include_shadow)
+}
+
+#[cfg(not(windows))]
+fn window_bounds_at(_x: i32, _y: i32, _include_shadow: bool) -> std::result::Result<(i32, i32, i32, i32), String> {
+    Err("Window capture only supported on Windows".into())
+}
+
+/// Bounds of the foreground window
+#[cfg(windows)]
+fn foreground_window_bounds(include_shadow: bool) -> std::result::Result<(i32, i32, i32, i32), String> {
+    use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.0.is_null() {
+        return Err("No foreground window".into());
+    }
+    window_bounds(hwnd, include_shadow)
+}
+
+#[cfg(not(windows))]
+fn foreground_window_bounds(_include_shadow: bool) -> std::result::Result<(i32, i32, i32, i32), String> {
+    Err("Window capture only supported on Windows".into())
+}
+
+/// Monitor geometry + stable OS identifier (for the frontend / scripting)
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct MonitorInfo {
+    /// Positional index, same as `--only-monitor`
+    pub index: usize,
+    /// OS device name (e.g. \\.\DISPLAY2) - stays the same when other monitors are reconnected
+    pub id: String,
+    /// Human-readable monitor name for settings UIs (falls back to id)
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f32,
+    pub is_primary: bool,
+}
+
+impl MonitorInfo {
+    /// Whether a virtual-desktop point lies on this monitor (right/bottom edges exclusive)
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.x + self.width as i32 && y >= self.y && y < self.y + self.height as i32
+    }
+}
+
+/// Monitor containing a virtual-desktop point (None for gaps between monitors / off-screen)
+fn monitor_at(monitors: &[MonitorInfo], x: i32, y: i32) -> Option<&MonitorInfo> {
+    monitors.iter().find(|m| m.contains(x, y))
+}
+
+/// Physical-pixel rect (x, y, w, h) from Win32 (per-monitor DPI aware) → overlay virtual coords
+/// [min_x, min_y, max_x, max_y], which use xcap's logical monitor size with physical = logical × scale.
+/// The monitor containing the rect's top-left defines the scale; None when it is on no monitor.
+fn physical_to_virtual_rect(monitors: &[MonitorInfo], (x, y, w, h): (i32, i32, i32, i32)) -> Option<[f32; 4]> {
+    let monitor = monitors.iter().find(|m| {
+        let scale = m.scale_factor as f64;
+        let phys_w = (m.width as f64 * scale) as i32;
+        let phys_h = (m.height as f64 * scale) as i32;
+        x >= m.x && x < m.x + phys_w && y >= m.y && y < m.y + phys_h
+    })?;
+    let scale = monitor.scale_factor.max(0.1);
+    let to_virtual = |p: i32, origin: i32| origin as f32 + (p - origin) as f32 / scale;
+
+    Some([
+        to_virtual(x, monitor.x),
+        to_virtual(y, monitor.y),
+        to_virtual(x + w, monitor.x),
+        to_virtual(y + h, monitor.y),
+    ])
+}
 
-    let monitors = Monitor::all()
+/// Shared enumeration: all monitors sorted by (x, y), OS name as tiebreak for stacked screens
+/// (same order as overlay_egui's capture, so indices are stable between the two)
+fn sorted_monitors() -> std::result::Result<Vec<Monitor>, String> {
+    let mut monitors = Monitor::all()
         .map_err(|e| format!("Failed to get monitors: {}", e))?;
+    monitors.sort_by_key(|m| (m.x().unwrap_or(0), m.y().unwrap_or(0), m.name().unwrap_or_default()));
+    Ok(monitors)
+}
+
+/// Sort by (x, y, id) - the same key as sorted_monitors and the overlay - and renumber 0..n
+fn sort_monitor_infos(monitors: &mut [MonitorInfo]) {
+    monitors.sort_by(|a, b| (a.x, a.y, &a.id).cmp(&(b.x, b.y, &b.id)));
+    for (index, monitor) in monitors.iter_mut().enumerate() {
+        monitor.index = index;
+    }
+}
 
-    // Sort monitors by X position (left to right) for consistent indexing
-    let mut monitors: Vec<_> = monitors.into_iter().enumerate().collect();
-    monitors.sort_by_key(|(_, m)| m.x().unwrap_or(0));
+fn monitor_infos() -> std::result::Result<Vec<MonitorInfo>, String> {
+    let mut infos: Vec<MonitorInfo> = Monitor::all()
+        .map_err(|e| format!("Failed to get monitors: {}", e))?
+        .iter()
+        .map(|m| MonitorInfo {
+            index: 0,
+            id: m.name().unwrap_or_default(),
+            name: m.friendly_name()
+                .ok()
+                .filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| m.name().unwrap_or_default()),
+            x: m.x().unwrap_or(0),
+            y: m.y().unwrap_or(0),
+            width: m.width().unwrap_or(1920),
+            height: m.height().unwrap_or(1080),
+            scale_factor: m.scale_factor().unwrap_or(1.0),
+            is_primary: m.is_primary().unwrap_or(false),
+        })
+        .collect();
+    sort_monitor_infos(&mut infos);
+    Ok(infos)
+}
+
+/// Detect which monitor contains the cursor
+fn detect_monitor_at_cursor() -> std::result::Result<usize, String> {
+    let cursor_pos = get_cursor_position()?;
 
-    for (idx, monitor) in monitors.iter() {
-        let x = monitor.x().unwrap_or(0);
-        let y = monitor.y().unwrap_or(0);
-        let w = monitor.width().unwrap_or(1920) as i32;
-        let h = monitor.height().unwrap_or(1080) as i32;
+    // Sorted by (x, y, name) for consistent indexing with the overlay
+    let monitors = monitor_infos()?;
 
+    for monitor in monitors.iter() {
         tracing::debug!(
             "Monitor {}: bounds ({}, {}) → ({}, {})",
-            idx, x, y, x + w, y + h
+            monitor.index, monitor.x, monitor.y,
+            monitor.x + monitor.width as i32, monitor.y + monitor.height as i32
         );
+    }
 
-        if cursor_pos.0 >= x && cursor_pos.0 < x + w &&
-           cursor_pos.1 >= y && cursor_pos.1 < y + h {
-            tracing::info!(
-                "✅ Cursor at ({}, {}) is on Monitor {}",
-                cursor_pos.0, cursor_pos.1, idx
-            );
-            return Ok(*idx);
-        }
+    if let Some(monitor) = monitor_at(&monitors, cursor_pos.0, cursor_pos.1) {
+        tracing::info!(
+            "✅ Cursor at ({}, {}) is on Monitor {}",
+            cursor_pos.0, cursor_pos.1, monitor.index
+        );
+        return Ok(monitor.index);
     }
 
     tracing::warn!(
@@ -69,58 +216,483 @@ fn detect_monitor_at_cursor() -> std::result::Result<usize, String> {
     Ok(0) // Fallback to primary monitor
 }
 
-/// F10 → Launch overlay for ACTIVE monitor (where cursor is)
-#[tauri::command]
-pub async fn launch_screenshot_overlay_active_monitor() -> std::result::Result<String, String> {
-    let monitor_index = detect_monitor_at_cursor()?;
-
-    tracing::info!("🚀 Launching overlay for active Monitor {}...", monitor_index);
-
-    let exe_path = std::env::current_exe()
+fn overlay_exe_path() -> std::result::Result<PathBuf, String> {
+    Ok(std::env::current_exe()
         .map_err(|e| format!("Failed to get current exe: {}", e))?
         .parent()
         .ok_or("No parent directory")?
-        .join("overlay_egui.exe");
+        .join("overlay_egui.exe"))
+}
+
+fn last_monitor_path(app: &AppHandle) -> PathBuf {
+    app.path().app_data_dir().unwrap().join("last-monitor.json")
+}
+
+/// Monitor index of the previous single-monitor overlay (None = never recorded / unreadable)
+fn read_last_monitor(app: &AppHandle) -> Option<usize> {
+    let data: serde_json::Value = serde_json::from_str(&fs::read_to_string(last_monitor_path(app)).ok()?).ok()?;
+    data.get("monitor_index")?.as_u64().map(|i| i as usize)
+}
+
+fn write_last_monitor(app: &AppHandle, monitor_index: usize) -> Result<()> {
+    let p = last_monitor_path(app);
+    if let Some(parent) = p.parent() { fs::create_dir_all(parent)?; }
+    fs::write(p, serde_json::to_vec_pretty(&serde_json::json!({ "monitor_index": monitor_index }))?)?;
+    Ok(())
+}
+
+fn last_selection_path(app: &AppHandle) -> PathBuf {
+    app.path().app_data_dir().unwrap().join("last-selection.json")
+}
+
+/// Ostatnie potwierdzone zaznaczenie overlay [min_x, min_y, max_x, max_y] (wirtualne współrzędne)
+fn read_last_selection(app: &AppHandle) -> Option<[f32; 4]> {
+    let data: serde_json::Value = serde_json::from_str(&fs::read_to_string(last_selection_path(app)).ok()?).ok()?;
+    serde_json::from_value(data.get("selection_rect")?.clone()).ok()
+}
+
+fn write_last_selection(app: &AppHandle, rect: [f32; 4]) -> Result<()> {
+    let p = last_selection_path(app);
+    if let Some(parent) = p.parent() { fs::create_dir_all(parent)?; }
+    fs::write(p, serde_json::to_vec_pretty(&serde_json::json!({ "selection_rect": rect }))?)?;
+    Ok(())
+}
 
+/// Zaznaczenie do wstępnego narysowania w overlay (reuse_last_selection), o ile nadal mieści się
+/// w wirtualnym pulpicie - układ monitorów mógł się zmienić od ostatniego razu
+fn remembered_selection(app: &AppHandle) -> Option<[f32; 4]> {
+    if !screenshot_config::load_config(app).reuse_last_selection {
+        return None;
+    }
+    let rect @ [x1, y1, x2, y2] = read_last_selection(app)?;
+    let (min_x, min_y, max_x, max_y) = virtual_desktop_bounds(&monitor_infos().ok()?)?;
+    let fits = x1 < x2 && y1 < y2
+        && x1 >= min_x as f32 && y1 >= min_y as f32
+        && x2 <= max_x as f32 && y2 <= max_y as f32;
+    if !fits {
+        tracing::warn!("⚠️ Remembered selection {:?} no longer fits the virtual desktop, ignoring", rect);
+        return None;
+    }
+    Some(rect)
+}
+
+/// How long we keep waiting for the user to finish an overlay session before dropping the watcher
+const OVERLAY_RESULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+
+/// Session outcome written by overlay_egui's parent (`--result`), emitted as `screenshot-result`
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct OverlayResult {
+    /// Final selection in virtual desktop coordinates [min_x, min_y, max_x, max_y]
+    pub selection: Option<[f32; 4]>,
+    pub saved_path: Option<String>,
+    /// saved / copied / text / pinned / color (None = cancelled)
+    pub action: Option<String>,
+    /// Every page written when the capture was split (empty = single file in saved_path)
+    pub saved_pages: Vec<String>,
+    /// Text recognized by the overlay's OCR (T)
+    pub text: Option<String>,
+    /// Pixel size of the image the overlay copied to the clipboard
+    pub copied_size: Option<[u32; 2]>,
+    pub cancelled: bool,
+}
+
+/// Whether a process with this PID is still running
+#[cfg(windows)]
+fn process_alive(pid: u32) -> bool {
+    use windows::Win32::{
+        Foundation::{CloseHandle, STILL_ACTIVE},
+        System::Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION},
+    };
+
+    unsafe {
+        let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+            return false;
+        };
+        let mut exit_code = 0u32;
+        let alive = GetExitCodeProcess(handle, &mut exit_code).is_ok() && exit_code == STILL_ACTIVE.0 as u32;
+        let _ = CloseHandle(handle);
+        alive
+    }
+}
+
+#[cfg(not(windows))]
+fn process_alive(pid: u32) -> bool {
+    Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+/// An overlay parent holds overlay_egui's single-instance lock (a lock whose PID is gone is stale)
+fn overlay_running() -> bool {
+    fs::read_to_string(std::env::temp_dir().join("egui_overlay.lock"))
+        .ok()
+        .and_then(|pid| pid.trim().parse::<u32>().ok())
+        .is_some_and(process_alive)
+}
+
+/// Spawn overlay_egui.exe in parent mode with `args` (+ config and result file) and watch it
+/// on a background thread: once it exits, its result is emitted as `screenshot-result`
+fn spawn_overlay(app: &AppHandle, args: &[String]) -> std::result::Result<PathBuf, String> {
+    if overlay_running() {
+        return Err("Screenshot overlay is already open".into());
+    }
+
+    let exe_path = overlay_exe_path()?;
     tracing::info!("📍 Overlay path: {}", exe_path.display());
 
-    // Launch overlay in PARENT MODE with --only-monitor flag
-    // Parent will capture all monitors but spawn child only for specified monitor
-    Command::new(&exe_path)
-        .arg("--only-monitor")
-        .arg(monitor_index.to_string())
+    let result_dir = std::env::temp_dir().join("aplikacja3").join("overlay-results");
+    fs::create_dir_all(&result_dir).map_err(|e| format!("Failed to create {}: {}", result_dir.display(), e))?;
+    let result_path = result_dir.join(format!("result_{}.json", chrono::Local::now().format("%Y%m%d_%H%M%S_%3f")));
+
+    // Explicit --select (capture_region_interactive etc.) wins over the remembered selection
+    let mut args = args.to_vec();
+    if !args.iter().any(|a| a == "--select") {
+        if let Some([x1, y1, x2, y2]) = remembered_selection(app) {
+            tracing::info!("🔁 Pre-seeding last selection ({}, {}) → ({}, {})", x1, y1, x2, y2);
+            args.extend(["--select".to_string(), format!("{},{},{},{}", x1, y1, x2, y2)]);
+        }
+    }
+
+    let child = Command::new(&exe_path)
+        .args(&args)
+        .arg("--config")
+        .arg(screenshot_config::config_path(app))
+        .arg("--on-release")
+        .arg(screenshot_config::load_config(app).default_action.as_arg())
+        .arg("--result")
+        .arg(&result_path)
         .spawn()
         .map_err(|e| format!("Failed to spawn overlay: {}", e))?;
 
+    watch_overlay_result(app.clone(), child, result_path);
+    Ok(exe_path)
+}
+
+fn watch_overlay_result(app: AppHandle, mut child: std::process::Child, result_path: PathBuf) {
+    std::thread::spawn(move || {
+        let started = std::time::Instant::now();
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    tracing::info!("🏁 Overlay exited ({})", status);
+                    break;
+                }
+                Ok(None) if started.elapsed() < OVERLAY_RESULT_TIMEOUT => {
+                    std::thread::sleep(std::time::Duration::from_millis(200));
+                }
+                Ok(None) => {
+                    tracing::warn!("⚠️ Overlay still open after {:?}, no longer waiting for its result", OVERLAY_RESULT_TIMEOUT);
+                    return;
+                }
+                Err(e) => {
+                    tracing::error!("❌ Failed to wait for overlay: {}", e);
+                    return;
+                }
+            }
+        }
+
+        // Missing/unreadable file = overlay crashed before reporting → treat as cancelled
+        let result = fs::read_to_string(&result_path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<OverlayResult>(&json).ok())
+            .unwrap_or_else(|| {
+                tracing::warn!("⚠️ Overlay exited without a result file");
+                OverlayResult { cancelled: true, ..OverlayResult::default() }
+            });
+        let _ = fs::remove_file(&result_path);
+
+        // Remember confirmed selections for reuse_last_selection; Escape without confirming forgets it
+        if result.cancelled {
+            let _ = fs::remove_file(last_selection_path(&app));
+        } else if let Some(rect) = result.selection.filter(|_| result.action.as_deref() != Some("color")) {
+            if let Err(e) = write_last_selection(&app, rect) {
+                tracing::warn!("⚠️ Failed to remember last selection: {}", e);
+            }
+        }
+
+        // Same event as capture_virtual_desktop_to_clipboard, so the UI handles both copy paths alike
+        if result.action.as_deref() == Some("copied") {
+            let _ = app.emit("screenshot-copied", result.copied_size.unwrap_or_default());
+        }
+        if let Some(text) = &result.text {
+            let _ = app.emit("ocr-result", text);
+        }
+        if !result.saved_pages.is_empty() {
+            let _ = app.emit("screenshot-pages-saved", &result.saved_pages);
+        }
+        let _ = app.emit("screenshot-result", &result);
+    });
+}
+
+/// Spawn the overlay in parent mode for a single monitor
+fn spawn_overlay_for_monitor(app: &AppHandle, monitor_index: usize) -> std::result::Result<PathBuf, String> {
+    if let Err(e) = write_last_monitor(app, monitor_index) {
+        tracing::warn!("⚠️ Failed to remember last monitor: {}", e);
+    }
+
+    // Launch overlay in PARENT MODE with --only-monitor flag
+    // Parent will capture all monitors but spawn child only for specified monitor
+    spawn_overlay(app, &["--only-monitor".into(), monitor_index.to_string()])
+}
+
+/// List connected monitors with geometry, stable ids, names and the primary flag.
+/// Indices come from the same (x, y, name) sort as the overlay, so they can be passed as `--only-monitor`.
+#[tauri::command]
+pub fn list_monitors() -> std::result::Result<Vec<MonitorInfo>, String> {
+    monitor_infos()
+}
+
+/// Where a virtual-desktop point lands
+#[derive(serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PointLocation {
+    /// In a gap between monitors or outside the virtual desktop
+    NotOnAnyMonitor,
+    /// Monitor index, stable id, bounds and DPI scale
+    OnMonitor { monitor: MonitorInfo },
+}
+
+/// Resolve which monitor (and DPI) a virtual-desktop point is on
+#[tauri::command]
+pub fn resolve_point(x: i32, y: i32) -> std::result::Result<PointLocation, String> {
+    let monitors = monitor_infos()?;
+    Ok(match monitor_at(&monitors, x, y) {
+        Some(monitor) => PointLocation::OnMonitor { monitor: monitor.clone() },
+        None => PointLocation::NotOnAnyMonitor,
+    })
+}
+
+/// Launch overlay for the monitor with the given stable id (robust to index shifts after reconnects)
+#[tauri::command]
+pub async fn capture_monitor_by_id(app: AppHandle, id: String) -> std::result::Result<String, String> {
+    let monitors = monitor_infos()?;
+    let monitor = monitors.iter().find(|m| m.id == id).ok_or_else(|| {
+        let available: Vec<&str> = monitors.iter().map(|m| m.id.as_str()).collect();
+        format!("Monitor '{}' not found. Available: {}", id, available.join(", "))
+    })?;
+
+    tracing::info!("🚀 Launching overlay for Monitor {} ({})...", monitor.index, monitor.id);
+    let exe_path = spawn_overlay_for_monitor(&app, monitor.index)?;
+
+    Ok(format!("Launched overlay for Monitor {} ({}) from {}", monitor.index, monitor.id, exe_path.display()))
+}
+
+/// Launch overlay for a monitor by positional index (same numbering as list_monitors / `--only-monitor`)
+#[tauri::command]
+pub async fn launch_screenshot_overlay_monitor(app: AppHandle, index: usize) -> std::result::Result<String, String> {
+    // Out of range must fail loudly - the overlay would otherwise fall back to monitor 0
+    let count = sorted_monitors()?.len();
+    if index >= count {
+        return Err(format!("Monitor index {} out of range ({} monitor(s) connected)", index, count));
+    }
+
+    tracing::info!("🚀 Launching overlay for Monitor {}...", index);
+    let exe_path = spawn_overlay_for_monitor(&app, index)?;
+
+    Ok(format!("Launched overlay for Monitor {} from {}", index, exe_path.display()))
+}
+
+/// F10 → Launch overlay for ACTIVE monitor (where cursor is)
+#[tauri::command]
+pub async fn launch_screenshot_overlay_active_monitor(app: AppHandle) -> std::result::Result<String, String> {
+    let monitor_index = if screenshot_config::load_config(&app).remember_last_monitor {
+        remembered_monitor(&app)?
+    } else {
+        detect_monitor_at_cursor()?
+    };
+
+    tracing::info!("🚀 Launching overlay for active Monitor {}...", monitor_index);
+
+    let exe_path = spawn_overlay_for_monitor(&app, monitor_index)?;
+
     Ok(format!("Launched overlay for Monitor {} from {}", monitor_index, exe_path.display()))
 }
 
-/// F11 → Launch overlay for ALL monitors
+/// Last used monitor, or cursor detection when none is stored or it's gone (unplugged)
+fn remembered_monitor(app: &AppHandle) -> std::result::Result<usize, String> {
+    let monitor_count = monitor_infos()?.len();
+    match read_last_monitor(app) {
+        Some(index) if index < monitor_count => {
+            tracing::info!("📌 Reusing last monitor {}", index);
+            Ok(index)
+        }
+        Some(index) => {
+            tracing::warn!("⚠️ Remembered monitor {} out of range ({} connected), detecting cursor", index, monitor_count);
+            detect_monitor_at_cursor()
+        }
+        None => detect_monitor_at_cursor(),
+    }
+}
+
+/// Overlay with the window under the cursor pre-selected (Enter to confirm, or drag to adjust).
+/// Uses tight DWM frame bounds unless window_shadow is enabled.
 #[tauri::command]
-pub async fn launch_screenshot_overlay_all_monitors() -> std::result::Result<String, String> {
-    tracing::info!("🚀 Launching overlay for ALL monitors...");
+pub async fn launch_screenshot_window_under_cursor(app: AppHandle) -> std::result::Result<String, String> {
+    let (cursor_x, cursor_y) = get_cursor_position()?;
+    let config = screenshot_config::load_config(&app);
+    let bounds = window_bounds_at(cursor_x, cursor_y, config.window_shadow)?;
 
-    let exe_path = std::env::current_exe()
-        .map_err(|e| format!("Failed to get current exe: {}", e))?
-        .parent()
-        .ok_or("No parent directory")?
-        .join("overlay_egui.exe");
+    let monitors = monitor_infos()?;
+    let [x1, y1, x2, y2] = physical_to_virtual_rect(&monitors, bounds)
+        .ok_or_else(|| format!("Window at {:?} is not on any monitor", bounds))?;
 
-    tracing::info!("📍 Overlay path: {}", exe_path.display());
+    // Single-monitor overlay when the window fits on the cursor's monitor, otherwise all monitors
+    let cursor_monitor = detect_monitor_at_cursor()?;
+    let fits = monitors.get(cursor_monitor).is_some_and(|m| {
+        x1 >= m.x as f32 && y1 >= m.y as f32
+            && x2 <= (m.x + m.width as i32) as f32 && y2 <= (m.y + m.height as i32) as f32
+    });
+
+    let mut args = Vec::new();
+    if fits {
+        args.extend(["--only-monitor".to_string(), cursor_monitor.to_string()]);
+    }
+    args.extend(["--select".to_string(), format!("{},{},{},{}", x1, y1, x2, y2)]);
+    let exe_path = spawn_overlay(&app, &args)?;
+
+    tracing::info!("🪟 Window under cursor: physical {:?} → virtual ({:.0}, {:.0}) → ({:.0}, {:.0})", bounds, x1, y1, x2, y2);
+    Ok(format!("Launched overlay with window pre-selected from {}", exe_path.display()))
+}
+
+/// Shift+F10 → overlay for the active monitor after a countdown (time to open menus etc.).
+/// Waits on a background thread and emits `screenshot-countdown` (seconds left) every second;
+/// the cursor monitor is detected only after the delay.
+#[tauri::command]
+pub fn launch_screenshot_overlay_delayed(app: AppHandle, seconds: u32) -> std::result::Result<String, String> {
+    if seconds > 60 {
+        return Err(format!("Delay too long: {}s (max 60s)", seconds));
+    }
+
+    tracing::info!("⏱️ Delayed capture in {}s...", seconds);
+    std::thread::spawn(move || {
+        for remaining in (1..=seconds).rev() {
+            let _ = app.emit("screenshot-countdown", remaining);
+            std::thread::sleep(std::time::Duration::from_secs(1));
+        }
+        let _ = app.emit("screenshot-countdown", 0u32);
+
+        let result = detect_monitor_at_cursor()
+            .and_then(|monitor_index| spawn_overlay_for_monitor(&app, monitor_index).map(|_| monitor_index));
+        match result {
+            Ok(monitor_index) => tracing::info!("🚀 Delayed overlay launched for Monitor {}", monitor_index),
+            Err(e) => tracing::error!("❌ Delayed overlay launch failed: {}", e),
+        }
+    });
+
+    Ok(format!("Overlay will open in {}s", seconds))
+}
+
+/// F11 → Launch overlay for ALL monitors
+#[tauri::command]
+pub async fn launch_screenshot_overlay_all_monitors(app: AppHandle) -> std::result::Result<String, String> {
+    tracing::info!("🚀 Launching overlay for ALL monitors...");
 
     // Launch overlay WITHOUT --monitor argument (parent process mode)
-    Command::new(&exe_path)
-        .spawn()
-        .map_err(|e| format!("Failed to spawn overlay: {}", e))?;
+    let exe_path = spawn_overlay(&app, &[])?;
 
     Ok(format!("Launched overlay for all monitors from {}", exe_path.display()))
 }
 
+/// Color picker on the active monitor: click copies #RRGGBB to the clipboard and closes the overlay
+#[tauri::command]
+pub async fn launch_color_picker(app: AppHandle) -> std::result::Result<String, String> {
+    let monitor_index = detect_monitor_at_cursor()?;
+
+    tracing::info!("🎨 Launching color picker on Monitor {}...", monitor_index);
+
+    let exe_path = spawn_overlay(&app, &["--only-monitor".into(), monitor_index.to_string(), "--picker".into()])?;
+
+    Ok(format!("Launched color picker for Monitor {} from {}", monitor_index, exe_path.display()))
+}
+
+/// Live-view overlay on all monitors: nothing is captured until the user presses F in the overlay,
+/// which freezes the frame for selecting (for animations/videos)
+#[tauri::command]
+pub async fn launch_screenshot_overlay_live(app: AppHandle) -> std::result::Result<String, String> {
+    tracing::info!("🚀 Launching live overlay for ALL monitors...");
+
+    let exe_path = spawn_overlay(&app, &["--live".into()])?;
+
+    Ok(format!("Launched live overlay for all monitors from {}", exe_path.display()))
+}
+
+/// Read-only mirror of overlay_egui's SharedState (only the fields we care about)
+#[derive(serde::Deserialize)]
+struct OverlayStateSnapshot {
+    selection_rect: Option<[f32; 4]>,
+    #[serde(default)]
+    is_dragging: bool,
+    #[serde(default)]
+    should_close: bool,
+}
+
+/// Live selection reported to the frontend while the overlay is open
+#[derive(serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OverlaySelection {
+    NoActiveSession,
+    Active {
+        /// Selection in virtual desktop coordinates [min_x, min_y, max_x, max_y]
+        selection_rect: Option<[f32; 4]>,
+        is_dragging: bool,
+    },
+}
+
+fn overlay_temp_dir() -> PathBuf {
+    std::env::temp_dir().join("egui_overlay")
+}
+
+/// PID procesu-rodzica overlay z nazwy katalogu sesji (<pid>_<czas>)
+fn session_owner(session_dir: &Path) -> Option<u32> {
+    session_dir.file_name()?.to_str()?.split('_').next()?.parse().ok()
+}
+
+/// state.json najnowszej sesji overlay (każda sesja ma własny podkatalog egui_overlay/<pid>_<czas>).
+/// Sesje, których proces-rodzic już nie żyje, to pozostałości po crashu - pomijamy je niezależnie od wieku.
+fn latest_overlay_state() -> Option<PathBuf> {
+    fs::read_dir(overlay_temp_dir())
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|dir| session_owner(dir).is_some_and(process_alive))
+        .map(|dir| dir.join("state.json"))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .max_by_key(|(_, modified)| *modified)
+        .map(|(path, _)| path)
+}
+
+/// Peek at the overlay's current selection without closing it (read-only)
+#[tauri::command]
+pub fn get_overlay_selection() -> std::result::Result<OverlaySelection, String> {
+    let state_file = match latest_overlay_state() {
+        Some(latest) => latest,
+        None => return Ok(OverlaySelection::NoActiveSession),
+    };
+
+    let json = match fs::read_to_string(&state_file) {
+        Ok(json) => json,
+        Err(_) => return Ok(OverlaySelection::NoActiveSession),
+    };
+    let state: OverlayStateSnapshot = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse overlay state: {}", e))?;
+
+    if state.should_close {
+        return Ok(OverlaySelection::NoActiveSession);
+    }
+
+    Ok(OverlaySelection::Active {
+        selection_rect: state.selection_rect,
+        is_dragging: state.is_dragging,
+    })
+}
+
 /// LEGACY: Old F8 hotkey (deprecated, use F10/F11 instead)
 #[tauri::command]
-pub async fn launch_screenshot_overlay() -> std::result::Result<String, String> {
+pub async fn launch_screenshot_overlay(app: AppHandle) -> std::result::Result<String, String> {
     tracing::warn!("⚠️ Using deprecated launch_screenshot_overlay (F8). Use F10/F11 instead.");
-    launch_screenshot_overlay_all_monitors().await
+    launch_screenshot_overlay_all_monitors(app).await
 }
 
 /// Zwraca prostą ścieżkę do pliku store z ostatnim screenshotem (używane przez /ss)
@@ -149,75 +721,318 @@ pub fn cancel_screenshot(_app: AppHandle) -> Result<()> {
     Ok(())
 }
 
+/// Przycina jednolite marginesy istniejącego pliku (nadpisuje go) i zwraca nowy rozmiar [w, h]
+#[tauri::command]
+pub fn autocrop_image(path: String, tolerance: u8) -> std::result::Result<[u32; 2], String> {
+    let img = screenshots::image::open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path, e))?
+        .to_rgba8();
+    let cropped = autocrop(&img, tolerance);
+    if cropped.dimensions() != img.dimensions() {
+        cropped.save(&path).map_err(|e| format!("Failed to save {}: {}", path, e))?;
+    }
+    tracing::info!(
+        "✂️ Auto-crop {}: {}×{} → {}×{}",
+        path, img.width(), img.height(), cropped.width(), cropped.height()
+    );
+    Ok([cropped.width(), cropped.height()])
+}
+
+/// Składa obraz z wielu ekranów na podstawie absolutnego prostokąta (x,y,w,h) - bez zapisu
+pub(crate) fn stitch_region(x: i32, y: i32, w: i32, h: i32) -> Result<RgbaImage> {
+    let selection = Bounds::new(x, y, w.max(0) as u32, h.max(0) as u32);
+    let screens = Screen::all()?;
+    let bounds: Vec<Bounds> = screens.iter()
+        .map(|s| Bounds::new(s.display_info.x, s.display_info.y, s.display_info.width, s.display_info.height))
+        .collect();
+
+    // z każdego ekranu tylko część nachodząca na zaznaczenie; skalowanie i sklejanie jak w overlay (region.rs)
+    let mut pieces = Vec::new();
+    for overlap in region::overlaps(selection, &bounds) {
+        let piece = screens[overlap.monitor].capture_area(
+            overlap.rel_x,
+            overlap.rel_y,
+            overlap.area.width,
+            overlap.area.height,
+        )?;
+        pieces.push((overlap, piece));
+    }
+
+    Ok(region::stitch(selection, pieces)?)
+}
+
+/// Aktywne okno → plik (bez overlay). Granice wg ustawienia window_shadow (domyślnie ciasne DWM).
+/// NOTE: wywołane z frontendu złapie okno aplikacji - przeznaczone głównie dla skrótu Alt+F10
+#[tauri::command]
+pub async fn capture_active_window(app: AppHandle) -> std::result::Result<String, String> {
+    let config = screenshot_config::load_config(&app);
+    let (x, y, w, h) = foreground_window_bounds(config.window_shadow)?;
+    if w <= 0 || h <= 0 {
+        return Err(format!("Foreground window has empty bounds {}×{}", w, h));
+    }
+
+    tracing::info!("🪟 Capturing active window ({}, {}) {}×{} (shadow: {})", x, y, w, h, config.window_shadow);
+    capture_region_and_save(app, x, y, w, h).map_err(|e| format!("Window capture failed: {}", e))
+}
+
+/// Kopiuje plik do zsynchronizowanego folderu i wrzuca link (prefix URL + nazwa, albo lokalna ścieżka)
+/// do schowka; emituje `screenshot-link-ready`
+fn share_file(app: &AppHandle, config: &screenshot_config::ScreenshotConfig, path: &Path) -> std::result::Result<String, String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let folder = config.share_folder.as_ref().ok_or("Share folder not configured")?;
+    let file_name = path.file_name().ok_or_else(|| format!("Not a file: {}", path.display()))?;
+    fs::create_dir_all(folder).map_err(|e| format!("Failed to create {}: {}", folder.display(), e))?;
+
+    let shared_path = folder.join(file_name);
+    if shared_path != path {
+        fs::copy(path, &shared_path).map_err(|e| format!("Failed to copy to {}: {}", shared_path.display(), e))?;
+    }
+
+    let link = match &config.share_url_prefix {
+        // spacje w nazwie pliku psułyby link
+        Some(prefix) => format!(
+            "{}/{}",
+            prefix.trim_end_matches('/'),
+            file_name.to_string_lossy().replace(' ', "%20")
+        ),
+        None => shared_path.to_string_lossy().to_string(),
+    };
+
+    app.clipboard()
+        .write_text(link.clone())
+        .map_err(|e| format!("Failed to write clipboard: {}", e))?;
+    tracing::info!("🔗 Share link ready: {}", link);
+    let _ = app.emit("screenshot-link-ready", &link);
+    Ok(link)
+}
+
+/// Payload eventu `screenshot-saved`
+#[derive(Clone, serde::Serialize)]
+pub struct ScreenshotSaved {
+    pub path: String,
+    /// Akcja wykonana po zapisie (open_in_default_app / reveal_in_explorer / none)
+    pub post_capture_action: PostCaptureAction,
+}
+
+/// Otwiera zapisany plik w domyślnej aplikacji albo pokazuje go w Eksploratorze.
+/// Ścieżki ze spacjami (C:\Users\Imię Nazwisko\...) muszą trafić jako jeden argument w cudzysłowie.
+#[cfg(windows)]
+fn run_post_capture_action(action: PostCaptureAction, path: &Path) -> std::result::Result<(), String> {
+    use std::os::windows::process::CommandExt;
+
+    let spawned = match action {
+        PostCaptureAction::None => return Ok(()),
+        // "" = tytuł okna dla `start`; ścieżkę cytuje sam Command (osobny argument)
+        PostCaptureAction::OpenInDefaultApp => Command::new("cmd")
+            .args(["/C", "start", ""])
+            .arg(path)
+            .spawn(),
+        // explorer nie rozumie "/select,C:\a b.png" w całości w cudzysłowie - cytujemy tylko ścieżkę
+        PostCaptureAction::RevealInExplorer => Command::new("explorer")
+            .raw_arg(format!("/select,\"{}\"", path.display()))
+            .spawn(),
+    };
+    spawned
+        .map(|_| ())
+        .map_err(|e| format!("Failed to run {:?} for {}: {}", action, path.display(), e))
+}
+
+#[cfg(not(windows))]
+fn run_post_capture_action(action: PostCaptureAction, path: &Path) -> std::result::Result<(), String> {
+    match action {
+        PostCaptureAction::None => Ok(()),
+        _ => Err(format!("{:?} is only supported on Windows ({})", action, path.display())),
+    }
+}
+
+/// Udostępnia istniejący zrzut (np. z overlay) przez skonfigurowany folder synchronizacji
+#[tauri::command]
+pub fn share_screenshot(app: AppHandle, path: String) -> std::result::Result<String, String> {
+    let config = screenshot_config::load_config(&app);
+    share_file(&app, &config, Path::new(&path))
+}
+
 /// Główny capture: składa obraz z wielu ekranów na podstawie absolutnego prostokąta (x,y,w,h)
-#[allow(dead_code)]
 pub fn capture_region_and_save(app: AppHandle, x: i32, y: i32, w: i32, h: i32) -> Result<String> {
-    let sel_x = x;
-    let sel_y = y;
-    let sel_w = w.max(0) as u32;
-    let sel_h = h.max(0) as u32;
-
-    // obraz wynikowy
-    let mut final_img: RgbaImage = RgbaImage::new(sel_w, sel_h);
-
-    // iterujemy po wszystkich ekranach i bierzemy część, która nachodzi na zaznaczenie
-    for screen in Screen::all()? {
-        let info = screen.display_info;
-        let sx = info.x;
-        let sy = info.y;
-        let sw = info.width as i32;
-        let sh = info.height as i32;
-
-        // prostokąty przecięcia w ABS współrzędnych
-        let ix = sel_x.max(sx);
-        let iy = sel_y.max(sy);
-        let ix2 = (sel_x + sel_w as i32).min(sx + sw);
-        let iy2 = (sel_y + sel_h as i32).min(sy + sh);
-
-        if ix2 <= ix || iy2 <= iy {
-            continue; // brak przecięcia
-        }
-
-        let inter_w = (ix2 - ix) as u32;
-        let inter_h = (iy2 - iy) as u32;
-
-        // współrzędne względne ekranowe
-        let rel_x = ix - sx;
-        let rel_y = iy - sy;
-
-        // capture_area: (x: i32, y: i32, width: u32, height: u32)
-        let piece: RgbaImage = screen.capture_area(rel_x, rel_y, inter_w, inter_h)?;
-
-        // gdzie wkleić w final_img (offset względem lewego-górnego rogu zaznaczenia)
-        let dx = (ix - sel_x) as i64;
-        let dy = (iy - sel_y) as i64;
-
-        // ręczne wklejenie pikseli - bez `imageops::overlay`, żeby uniknąć konfliktu wersji `image`
-        for yy in 0..inter_h {
-            for xx in 0..inter_w {
-                let px = piece.get_pixel(xx, yy);
-                final_img.put_pixel((dx as u32) + xx, (dy as u32) + yy, *px);
-            }
-        }
-    }
+    let mut final_img = stitch_region(x, y, w, h)?;
 
-    // zapisz PNG w %TEMP%\aplikacja3\screens\YYYYmmdd_HHMMSS.png
-    let mut out_dir = std::env::temp_dir();
-    out_dir.push("aplikacja3");
-    out_dir.push("screens");
-    fs::create_dir_all(&out_dir)?;
+    let config = screenshot_config::load_config(&app);
+    // auto-crop, zaokrąglone rogi i preset - ta sama ścieżka co zapis z overlay (screenshot_settings.rs)
+    final_img = config.post_process(final_img);
+    let format = config.output_format();
 
-    let ts = chrono::Local::now().format("%Y%m%d_%H%M%S");
-    let filename = format!("screenshot_{}.png", ts);
-    let out_path = out_dir.join(filename);
+    // zapisz w skonfigurowanym folderze i formacie (domyślnie %TEMP%\aplikacja3\screens) wg szablonu nazwy
+    let monitor = monitor_at(&monitor_infos()?, x, y)
+        .map(|m| m.index)
+        .unwrap_or(0);
+    let extension = format.extension();
+    let out_path = config.output_path(monitor, extension)?;
 
-    // zapis (RgbaImage ma .save())
-    final_img.save(&out_path)?;
+    // zapis - wysokie obrazy opcjonalnie dzielone na strony name_p01.png, name_p02.png...
+    let pages = save_paginated(&final_img, &out_path, format, config.jpeg_background, config.page_height, config.page_overlap)?;
+    let out_path = match pages.first() {
+        Some(first) => {
+            let _ = app.emit("screenshot-pages-saved", &pages);
+            first.clone()
+        }
+        None => out_path,
+    };
+
+    if config.capture_sound {
+        crate::capture_sound::play(config.capture_sound_volume, config.capture_sound_file.clone());
+    }
 
     // zapisz ścieżkę do store + emit event do frontu
     let out_str = out_path.to_string_lossy().to_string();
     let _ = write_last_screenshot(&app, &out_str);
-    let _ = app.emit("screenshot-saved", &out_str);
+    let _ = app.emit("screenshot-saved", ScreenshotSaved {
+        path: out_str.clone(),
+        post_capture_action: config.post_capture_action,
+    });
+
+    if let Err(e) = run_post_capture_action(config.post_capture_action, &out_path) {
+        tracing::error!("❌ Post-capture action failed: {}", e);
+    }
+
+    if config.share_folder.is_some() {
+        if let Err(e) = share_file(&app, &config, &out_path) {
+            tracing::error!("❌ Sharing failed: {}", e);
+        }
+    }
 
     Ok(out_str)
 }
+
+/// Granice wirtualnego pulpitu (min_x, min_y, max_x, max_y), jak calculate_virtual_desktop_bounds w overlay_egui
+fn virtual_desktop_bounds(monitors: &[MonitorInfo]) -> Option<(i32, i32, i32, i32)> {
+    Some((
+        monitors.iter().map(|m| m.x).min()?,
+        monitors.iter().map(|m| m.y).min()?,
+        monitors.iter().map(|m| m.x + m.width as i32).max()?,
+        monitors.iter().map(|m| m.y + m.height as i32).max()?,
+    ))
+}
+
+/// Prostokąt (x,y,w,h) wirtualnego pulpitu → plik, bez overlay (przyciski UI, skrypty, testy).
+/// Zwraca ścieżkę zapisanego pliku.
+#[tauri::command]
+pub async fn capture_region(app: AppHandle, x: i32, y: i32, w: i32, h: i32) -> std::result::Result<String, String> {
+    if w <= 0 || h <= 0 {
+        return Err(format!("Region size must be positive, got {}×{}", w, h));
+    }
+
+    let (min_x, min_y, max_x, max_y) = virtual_desktop_bounds(&monitor_infos()?).ok_or("No monitors found")?;
+    // i64 - x + w can't overflow
+    let outside = (x as i64) < min_x as i64
+        || (y as i64) < min_y as i64
+        || x as i64 + w as i64 > max_x as i64
+        || y as i64 + h as i64 > max_y as i64;
+    if outside {
+        return Err(format!(
+            "Region ({}, {}) {}×{} is outside the virtual desktop ({}, {}) → ({}, {})",
+            x, y, w, h, min_x, min_y, max_x, max_y
+        ));
+    }
+
+    tracing::info!("📸 Headless capture ({}, {}) {}×{}", x, y, w, h);
+    capture_region_and_save(app, x, y, w, h).map_err(|e| format!("Capture failed: {}", e))
+}
+
+/// Cały monitor (indeks jak w list_monitors / --only-monitor) → plik, bez overlay
+#[tauri::command]
+pub async fn capture_full_monitor(app: AppHandle, index: usize) -> std::result::Result<String, String> {
+    let monitors = monitor_infos()?;
+    let monitor = monitors
+        .get(index)
+        .ok_or_else(|| format!("Monitor {} not found ({} connected)", index, monitors.len()))?;
+
+    tracing::info!("📸 Headless capture of Monitor {} ({})", monitor.index, monitor.id);
+    capture_region_and_save(app, monitor.x, monitor.y, monitor.width as i32, monitor.height as i32)
+        .map_err(|e| format!("Capture failed: {}", e))
+}
+
+/// Cały wirtualny pulpit (wszystkie monitory) prosto do schowka - bez overlay i bez pliku
+#[tauri::command]
+pub async fn capture_virtual_desktop_to_clipboard(app: AppHandle) -> std::result::Result<String, String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let (min_x, min_y, max_x, max_y) = virtual_desktop_bounds(&monitor_infos()?).ok_or("No monitors found")?;
+
+    let mut img = stitch_region(min_x, min_y, max_x - min_x, max_y - min_y)
+        .map_err(|e| format!("Failed to capture virtual desktop: {}", e))?;
+
+    // zszyty pulpit bywa ogromny - opcjonalne zmniejszenie do max_output_dimension
+    let config = screenshot_config::load_config(&app);
+    if let Some(max_dim) = config.max_output_dimension {
+        let longest = img.width().max(img.height());
+        if longest > max_dim && max_dim > 0 {
+            let scale = max_dim as f64 / longest as f64;
+            let w = ((img.width() as f64 * scale).round() as u32).max(1);
+            let h = ((img.height() as f64 * scale).round() as u32).max(1);
+            tracing::info!("🖼️ Downscaling desktop {}×{} → {}×{}", img.width(), img.height(), w, h);
+            img = screenshots::image::imageops::resize(&img, w, h, screenshots::image::imageops::FilterType::Triangle);
+        }
+    }
+
+    let (w, h) = img.dimensions();
+    app.clipboard()
+        .write_image(&tauri::image::Image::new_owned(img.into_raw(), w, h))
+        .map_err(|e| format!("Failed to write image to clipboard: {}", e))?;
+
+    tracing::info!("📋 Copied virtual desktop ({}×{}) to clipboard", w, h);
+    let _ = app.emit("screenshot-copied", [w, h]);
+    Ok(format!("Copied {}×{} virtual desktop to clipboard", w, h))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(id: &str, x: i32, y: i32) -> MonitorInfo {
+        MonitorInfo {
+            index: usize::MAX,
+            id: id.into(),
+            name: id.into(),
+            x,
+            y,
+            width: 1920,
+            height: 1080,
+            scale_factor: 1.0,
+            is_primary: x == 0 && y == 0,
+        }
+    }
+
+    #[test]
+    fn sort_monitor_infos_orders_by_position_then_id() {
+        let mut monitors = vec![
+            monitor(r"\\.\DISPLAY3", 1920, 0),
+            monitor(r"\\.\DISPLAY2", -1920, 0),
+            monitor(r"\\.\DISPLAY4", 0, 0),
+            monitor(r"\\.\DISPLAY1", 0, 0),
+            monitor(r"\\.\DISPLAY5", 1920, -1080),
+        ];
+        sort_monitor_infos(&mut monitors);
+
+        let order: Vec<_> = monitors.iter().map(|m| (m.index, m.id.as_str())).collect();
+        assert_eq!(order, [
+            (0, r"\\.\DISPLAY2"),
+            (1, r"\\.\DISPLAY1"),
+            (2, r"\\.\DISPLAY4"),
+            (3, r"\\.\DISPLAY5"),
+            (4, r"\\.\DISPLAY3"),
+        ]);
+    }
+
+    #[test]
+    fn sort_monitor_infos_is_stable_across_enumeration_order() {
+        let mut forward = vec![monitor("A", 0, 0), monitor("B", 2560, 0), monitor("C", -1280, 200)];
+        let mut reversed: Vec<_> = forward.iter().rev().cloned().collect();
+        sort_monitor_infos(&mut forward);
+        sort_monitor_infos(&mut reversed);
+
+        let ids = |monitors: &[MonitorInfo]| monitors.iter().map(|m| (m.index, m.id.clone())).collect::<Vec<_>>();
+        assert_eq!(ids(&forward), ids(&reversed));
+        assert!(forward.iter().enumerate().all(|(position, m)| m.index == position));
+    }
+}
@@ -0,0 +1,204 @@
+// Also compiled into overlay_egui via #[path] (it deserializes the same screenshot-config.json
+// it gets with --config) - keep this file free of tauri imports. Commands live in screenshot_config.rs.
+use crate::image::RgbaImage;
+use crate::postprocess::{apply_post_process, autocrop, preset_format, round_corners, PostProcessOp, ScreenshotFormat};
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// What the overlay does when a selection drag is released
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionAction {
+    /// Save to output_dir and close
+    SaveFile,
+    /// Copy to clipboard and close
+    CopyClipboard,
+    /// Keep the overlay open for annotation (Enter / Ctrl+C finish it)
+    #[default]
+    StayOpen,
+}
+
+impl SelectionAction {
+    /// Value of overlay_egui's --on-release flag
+    pub fn as_arg(&self) -> &'static str {
+        match self {
+            SelectionAction::SaveFile => "save_file",
+            SelectionAction::CopyClipboard => "copy_clipboard",
+            SelectionAction::StayOpen => "stay_open",
+        }
+    }
+}
+
+/// What happens with a file right after capture_region_and_save writes it
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostCaptureAction {
+    #[default]
+    None,
+    /// Open in the default image viewer/editor
+    OpenInDefaultApp,
+    /// Open its folder in Explorer with the file selected
+    RevealInExplorer,
+}
+
+/// Persistent screenshot settings (stored as JSON in app_data_dir)
+///
+/// Every field has a default so older config files keep loading after new settings are added.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ScreenshotConfig {
+    /// Mask the corners of the saved image with transparency
+    pub round_corners: bool,
+    /// Corner radius in output pixels
+    pub corner_radius: u32,
+    /// Split captures taller than this into pages (None = single image)
+    pub page_height: Option<u32>,
+    /// Rows repeated at the top of each following page
+    pub page_overlap: u32,
+    /// Named post-processing pipelines
+    pub presets: BTreeMap<String, Vec<PostProcessOp>>,
+    /// Preset applied to every capture before saving
+    pub post_capture_preset: Option<String>,
+    /// Manual DPI scale per monitor index, read by overlay_egui's capture step
+    pub monitor_scale_overrides: BTreeMap<usize, f64>,
+    /// Where screenshots are saved (None = %TEMP%\aplikacja3\screens)
+    pub output_dir: Option<PathBuf>,
+    /// File name without extension; tokens: {date} {time} {counter} {monitor}
+    pub filename_template: String,
+    /// Downscale clipboard captures whose longest side exceeds this (None = never)
+    pub max_output_dimension: Option<u32>,
+    pub format: ScreenshotFormat,
+    /// RGB color transparent pixels are flattened onto for JPEG
+    pub jpeg_background: [u8; 3],
+    /// RGBA color of the overlay's composition guides, read by overlay_egui
+    pub guide_color: [u8; 4],
+    /// Trim uniform borders from every capture before saving
+    pub auto_crop: bool,
+    /// Max per-channel difference still treated as the border color
+    pub auto_crop_tolerance: u8,
+    /// BCP-47 OCR language (None = system locale), also read by overlay_egui
+    pub ocr_language: Option<String>,
+    /// Window captures use GetWindowRect (with shadow/resize border) instead of tight DWM frame bounds
+    pub window_shadow: bool,
+    /// Play a shutter sound after each saved capture (also read by overlay_egui)
+    pub capture_sound: bool,
+    /// 0.0 - 1.0
+    pub capture_sound_volume: f32,
+    /// Custom sound file (None = built-in shutter)
+    pub capture_sound_file: Option<PathBuf>,
+    /// Synced folder (Dropbox/OneDrive...) every capture is copied into (None = sharing off)
+    pub share_folder: Option<PathBuf>,
+    /// Public URL of share_folder; the link is prefix + file name (None = copy the local path)
+    pub share_url_prefix: Option<String>,
+    /// F10 reuses the monitor of the previous single-monitor overlay instead of detecting the cursor
+    pub remember_last_monitor: bool,
+    /// Overlay drags smaller than this (either side, px) are flagged red and discarded, read by overlay_egui
+    pub min_selection_size: u32,
+    /// Cap on overlay selections [width, height] in px (None = unlimited), read by overlay_egui
+    pub max_selection_size: Option<[u32; 2]>,
+    /// Overlay behavior on drag release, passed as --on-release
+    pub default_action: SelectionAction,
+    /// Open/reveal the saved file after region captures
+    pub post_capture_action: PostCaptureAction,
+    /// Pre-seed the overlay with the last confirmed selection (stored in last-selection.json)
+    pub reuse_last_selection: bool,
+}
+
+impl Default for ScreenshotConfig {
+    fn default() -> Self {
+        Self {
+            round_corners: false,
+            corner_radius: 12,
+            page_height: None,
+            page_overlap: 50,
+            presets: BTreeMap::new(),
+            post_capture_preset: None,
+            monitor_scale_overrides: BTreeMap::new(),
+            output_dir: None,
+            filename_template: DEFAULT_FILENAME_TEMPLATE.into(),
+            max_output_dimension: Some(8192),
+            format: ScreenshotFormat::Png,
+            jpeg_background: [255, 255, 255],
+            guide_color: [255, 255, 255, 160],
+            auto_crop: false,
+            auto_crop_tolerance: 8,
+            ocr_language: None,
+            window_shadow: false,
+            capture_sound: false,
+            capture_sound_volume: 0.6,
+            capture_sound_file: None,
+            share_folder: None,
+            share_url_prefix: None,
+            remember_last_monitor: false,
+            min_selection_size: 5,
+            max_selection_size: None,
+            default_action: SelectionAction::StayOpen,
+            post_capture_action: PostCaptureAction::None,
+            reuse_last_selection: false,
+        }
+    }
+}
+
+/// Matches the historical screenshot_YYYYmmdd_HHMMSS.png naming
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "screenshot_{date}_{time}";
+
+impl ScreenshotConfig {
+    /// Post-capture steps for saved files: auto-crop, rounded corners, then the active preset
+    pub fn post_process(&self, mut img: RgbaImage) -> RgbaImage {
+        if self.auto_crop {
+            img = autocrop(&img, self.auto_crop_tolerance);
+        }
+        if self.round_corners {
+            round_corners(&mut img, self.corner_radius);
+        }
+        if let Some(ops) = self.active_preset() {
+            img = apply_post_process(img, ops);
+        }
+        img
+    }
+
+    /// Steps of post_capture_preset (None = no preset, or it no longer exists)
+    pub fn active_preset(&self) -> Option<&[PostProcessOp]> {
+        let name = self.post_capture_preset.as_ref()?;
+        let ops = self.presets.get(name);
+        if ops.is_none() {
+            tracing::warn!("⚠️ Post-capture preset '{}' not found, skipping", name);
+        }
+        ops.map(Vec::as_slice)
+    }
+
+    /// Configured format, unless the active preset has a Format step (the last one wins)
+    pub fn output_format(&self) -> ScreenshotFormat {
+        self.active_preset().and_then(preset_format).unwrap_or(self.format)
+    }
+
+    /// Configured output directory or the default temp location
+    pub fn resolved_output_dir(&self) -> PathBuf {
+        self.output_dir.clone().unwrap_or_else(|| {
+            std::env::temp_dir().join("aplikacja3").join("screens")
+        })
+    }
+
+    /// Build a non-colliding output path in the output dir (creates the dir; appends _1, _2... on collision)
+    pub fn output_path(&self, monitor: usize, extension: &str) -> std::io::Result<PathBuf> {
+        let dir = self.resolved_output_dir();
+        fs::create_dir_all(&dir)?;
+
+        // {counter} = entries already in the folder + 1. Every entry counts - _pNN pages, _1 collision
+        // copies and files that didn't come from this app - so it only grows, but isn't a capture count.
+        let counter = fs::read_dir(&dir).map(|entries| entries.count()).unwrap_or(0) + 1;
+        let now = chrono::Local::now();
+        let stem = self.filename_template
+            .replace("{date}", &now.format("%Y%m%d").to_string())
+            .replace("{time}", &now.format("%H%M%S").to_string())
+            .replace("{counter}", &format!("{:03}", counter))
+            .replace("{monitor}", &monitor.to_string());
+
+        let mut path = dir.join(format!("{}.{}", stem, extension));
+        let mut suffix = 1;
+        while path.exists() {
+            path = dir.join(format!("{}_{}.{}", stem, suffix, extension));
+            suffix += 1;
+        }
+        Ok(path)
+    }
+}
@@ -0,0 +1,67 @@
+use tauri::{AppHandle, Emitter, Manager};
+use crate::screenshot_config::{self, ScreenshotConfig};
+use crate::vtt_config::{self, VttConfig, VttConfigState};
+
+/// Which group of settings `reset_settings` restores
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SettingsScope {
+    All,
+    Hotkeys,
+    Screenshot,
+    Expansion,
+    Overlay,
+    Vtt,
+}
+
+fn reset_hotkeys(app: &AppHandle) -> std::result::Result<(), String> {
+    crate::set_hotkeys_muted(app.clone(), app.state::<crate::HotkeysState>(), false)?;
+    crate::reset_hotkey_bindings(app)
+}
+
+fn reset_screenshot(app: &AppHandle) -> std::result::Result<(), String> {
+    screenshot_config::save_config(app, &ScreenshotConfig::default())
+}
+
+/// Resume text expansion (the pause flag is the only expansion state kept outside the snippet store)
+fn reset_expansion(app: &AppHandle) {
+    crate::set_expansion_paused(app.clone(), app.state::<crate::simple_expansion::SimpleExpansionState>(), false);
+}
+
+fn reset_vtt(app: &AppHandle) -> std::result::Result<(), String> {
+    let config = VttConfig::default();
+    vtt_config::save_config(app, &config)?;
+    *app.state::<VttConfigState>().0.write().unwrap() = config;
+    Ok(())
+}
+
+/// Restore built-in defaults for one scope (or everything) and re-apply them live.
+/// Snippet content is only eligible with `scope == All`; scopes with nothing to reset return Err.
+#[tauri::command]
+pub fn reset_settings(app: AppHandle, scope: SettingsScope, include_snippets: bool) -> std::result::Result<(), String> {
+    if include_snippets && scope != SettingsScope::All {
+        return Err(format!("include_snippets only applies to scope All, not {:?}", scope));
+    }
+    if include_snippets {
+        // Checked before anything is reset, so a rejected request leaves every setting as it was
+        return Err("Resetting snippet content is not supported yet; remove snippets from the snippet list instead".into());
+    }
+
+    match scope {
+        SettingsScope::All => {
+            reset_hotkeys(&app)?;
+            reset_screenshot(&app)?;
+            reset_expansion(&app);
+            reset_vtt(&app)?;
+        }
+        SettingsScope::Hotkeys => reset_hotkeys(&app)?,
+        SettingsScope::Screenshot => reset_screenshot(&app)?,
+        SettingsScope::Expansion => reset_expansion(&app),
+        SettingsScope::Vtt => reset_vtt(&app)?,
+        // Overlay toggles live only in the per-capture state - nothing persisted to reset
+        SettingsScope::Overlay => return Err("Overlay has no persisted settings to reset".into()),
+    }
+
+    tracing::info!("♻️ Settings reset to defaults (scope: {:?})", scope);
+    let _ = app.emit("settings-reset", scope);
+    Ok(())
+}
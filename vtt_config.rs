@@ -0,0 +1,123 @@
+use tauri::{AppHandle, Manager};
+use std::{fs, path::PathBuf, sync::RwLock};
+
+/// Languages the transcription model can be pinned to (ISO 639-1)
+const SUPPORTED_LANGUAGES: &[&str] = &[
+    "en", "pl", "de", "fr", "es", "it", "pt", "nl", "cs", "sk", "uk", "ru", "sv", "no", "da", "fi", "ja", "zh", "ko",
+];
+
+/// Whisper model sizes, smallest (fastest) first
+const SUPPORTED_MODELS: &[&str] = &["tiny", "base", "small", "medium", "large"];
+
+/// How the VTT hotkey drives recording
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VttMode {
+    /// Press starts, next press stops
+    #[default]
+    Toggle,
+    /// Record while the key is held, stop on release
+    PushToTalk,
+}
+
+/// Persistent voice-to-text settings (stored as JSON in app_data_dir)
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct VttConfig {
+    /// ISO 639-1 code; None = auto-detect
+    pub language: Option<String>,
+    pub model: String,
+    pub mode: VttMode,
+}
+
+impl Default for VttConfig {
+    fn default() -> Self {
+        Self {
+            language: None,
+            model: "base".into(),
+            mode: VttMode::Toggle,
+        }
+    }
+}
+
+/// Managed copy of the config so transcription doesn't hit the disk every time
+pub struct VttConfigState(pub RwLock<VttConfig>);
+
+pub fn config_path(app: &AppHandle) -> PathBuf {
+    app.path().app_data_dir().unwrap().join("vtt-config.json")
+}
+
+/// Load config, falling back to defaults when the file is missing or unreadable.
+/// A language or model that is no longer supported falls back to auto-detect / the default model.
+pub fn load_config(app: &AppHandle) -> VttConfig {
+    let mut config: VttConfig = fs::read_to_string(config_path(app))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+
+    if let Some(lang) = config.language.as_deref().filter(|l| !SUPPORTED_LANGUAGES.contains(l)) {
+        tracing::warn!("⚠️ Unsupported VTT language '{}' in config, using auto-detect", lang);
+        config.language = None;
+    }
+    if !SUPPORTED_MODELS.contains(&config.model.as_str()) {
+        tracing::warn!("⚠️ Unsupported VTT model '{}' in config, using default", config.model);
+        config.model = VttConfig::default().model;
+    }
+    config
+}
+
+pub fn save_config(app: &AppHandle, config: &VttConfig) -> std::result::Result<(), String> {
+    let path = config_path(app);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let json = serde_json::to_vec_pretty(config)
+        .map_err(|e| format!("Failed to serialize VTT config: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Apply a change to the managed config and persist it
+fn update_config(app: &AppHandle, change: impl FnOnce(&mut VttConfig)) -> std::result::Result<(), String> {
+    let state = app.state::<VttConfigState>();
+    let mut config = state.0.write().unwrap();
+    change(&mut config);
+    save_config(app, &config)
+}
+
+#[tauri::command]
+pub fn get_vtt_config(state: tauri::State<'_, VttConfigState>) -> VttConfig {
+    state.0.read().unwrap().clone()
+}
+
+/// Pin the transcription language (e.g. "pl"); "auto" or an empty string = auto-detect
+#[tauri::command]
+pub fn set_vtt_language(app: AppHandle, lang: String) -> std::result::Result<(), String> {
+    let lang = lang.trim().to_lowercase();
+    let language = match lang.as_str() {
+        "" | "auto" => None,
+        code if SUPPORTED_LANGUAGES.contains(&code) => Some(lang),
+        _ => {
+            return Err(format!(
+                "Unsupported language '{}' (supported: auto, {})",
+                lang,
+                SUPPORTED_LANGUAGES.join(", ")
+            ))
+        }
+    };
+    update_config(&app, |config| config.language = language)
+}
+
+/// Pick the transcription model size (tiny/base/small/medium/large)
+#[tauri::command]
+pub fn set_vtt_model(app: AppHandle, model: String) -> std::result::Result<(), String> {
+    if !SUPPORTED_MODELS.contains(&model.as_str()) {
+        return Err(format!("Unsupported model '{}' (supported: {})", model, SUPPORTED_MODELS.join(", ")));
+    }
+    update_config(&app, |config| config.model = model)
+}
+
+/// Switch between toggle and push-to-talk recording
+#[tauri::command]
+pub fn set_vtt_mode(app: AppHandle, mode: VttMode) -> std::result::Result<(), String> {
+    update_config(&app, |config| config.mode = mode)
+}